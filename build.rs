@@ -0,0 +1,35 @@
+//! Detect library APIs that are newer than the oldest compilers we test with.
+//!
+//! Integrations for these APIs are enabled automatically when the compiler
+//! supports them, so they don't need to be opted into.
+
+use std::{env, process::Command, str};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+
+    let minor = match rustc_minor_version() {
+        Some(minor) => minor,
+        None => return,
+    };
+
+    // `core::num::Saturating` was stabilized in 1.74
+    if minor >= 74 {
+        println!("cargo:rustc-cfg=value_bag_saturating");
+    }
+
+    // `core::net` was stabilized in 1.77
+    if minor >= 77 {
+        println!("cargo:rustc-cfg=value_bag_core_net");
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = str::from_utf8(&output.stdout).ok()?;
+
+    // The version looks like `rustc 1.77.0 (aedd173a2 2024-03-17)`
+    version.split('.').nth(1)?.parse().ok()
+}
@@ -0,0 +1,43 @@
+//! Capture values without `std`.
+//!
+//! This crate is built for a target without `std`, so any capturing
+//! that accidentally depends on `std` will fail to compile.
+
+#![no_std]
+
+use core::{
+    fmt::{self, Write},
+    net::{Ipv4Addr, SocketAddr},
+};
+
+use value_bag::ValueBag;
+
+struct Buf {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+pub fn socket_addr() -> bool {
+    let addr = SocketAddr::from((Ipv4Addr::new(127, 0, 0, 1), 8080));
+
+    let mut buf = Buf {
+        buf: [0; 64],
+        len: 0,
+    };
+
+    write!(buf, "{}", ValueBag::from_socket_addr(&addr)).is_ok()
+        && &buf.buf[..buf.len] == b"127.0.0.1:8080"
+}
@@ -40,7 +40,7 @@
 
 use crate::std::fmt;
 
-use super::internal::{Internal, InternalVisitor};
+use super::internal::{Internal, InternalVisitor, Unborrowed};
 use super::{Error, ValueBag};
 
 impl<'v> ValueBag<'v> {
@@ -53,6 +53,104 @@ impl<'v> ValueBag<'v> {
             inner: Internal::Fill(value),
         }
     }
+
+    /// Get a value from a function that produces it on demand.
+    ///
+    /// The function will be called each time the value is inspected.
+    pub const fn from_fn<F>(value: &'v F) -> Self
+    where
+        F: Fn() -> ValueBag<'v>,
+    {
+        ValueBag {
+            inner: Internal::Fill(FnValue::new_ref(value)),
+        }
+    }
+
+    /// Get a value from a fillable slot, but only some of the time.
+    ///
+    /// The value will be captured with probability `rate`, which should be between
+    /// `0.0` and `1.0`. Otherwise the empty value is returned and `value` will never
+    /// be filled. The decision is made once, when this method is called, so every
+    /// inspection of the returned value agrees.
+    ///
+    /// This method uses a random number from the standard library. See
+    /// [`ValueBag::from_fill_sampled_with`] for a way to provide random numbers.
+    #[cfg(feature = "std")]
+    pub fn from_fill_sampled<T>(rate: f64, value: &'v T) -> Self
+    where
+        T: Fill,
+    {
+        ValueBag::from_fill_sampled_with(rate, random, value)
+    }
+
+    /// Get a value from a fillable slot, but only some of the time.
+    ///
+    /// This method is like [`ValueBag::from_fill_sampled`], but uses `sample` to
+    /// produce a random number between `0.0` and `1.0`. It's only called when `rate`
+    /// is between `0.0` and `1.0`.
+    pub fn from_fill_sampled_with<T>(rate: f64, sample: impl FnOnce() -> f64, value: &'v T) -> Self
+    where
+        T: Fill,
+    {
+        let capture = if rate >= 1.0 {
+            true
+        } else if rate > 0.0 {
+            sample() < rate
+        } else {
+            false
+        };
+
+        if capture {
+            ValueBag::from_fill(value)
+        } else {
+            ValueBag::empty()
+        }
+    }
+}
+
+impl<'v, F> From<&'v F> for ValueBag<'v>
+where
+    F: Fn() -> ValueBag<'v>,
+{
+    #[inline]
+    fn from(v: &'v F) -> Self {
+        ValueBag::from_fn(v)
+    }
+}
+
+#[repr(transparent)]
+struct FnValue<F>(F);
+
+impl<F> FnValue<F> {
+    const fn new_ref(v: &F) -> &FnValue<F> {
+        // SAFETY: `FnValue<F>` and `F` have the same ABI
+        unsafe { &*(v as *const F as *const FnValue<F>) }
+    }
+}
+
+impl<'v, F> Fill for FnValue<F>
+where
+    F: Fn() -> ValueBag<'v>,
+{
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        let value = (self.0)();
+
+        slot.fill(|visitor| value.inner.internal_visit(Unborrowed(visitor)))
+    }
+}
+
+#[cfg(feature = "std")]
+fn random() -> f64 {
+    use crate::std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+
+    // `RandomState` is seeded randomly, so hashing nothing with it
+    // produces a random number
+    let bits = RandomState::new().build_hasher().finish();
+
+    (bits >> 11) as f64 / (1u64 << 53) as f64
 }
 
 /// A type that requires extra work to convert into a [`ValueBag`](../struct.ValueBag.html).
@@ -132,6 +230,22 @@ mod tests {
         assert_eq!("1", ValueBag::from_fill(&TestFill).to_string());
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fill_fn() {
+        let a = 20u64;
+        let b = 22u64;
+        let sum = || ValueBag::from(a + b);
+
+        assert_eq!(Some(42), ValueBag::from_fn(&sum).to_u64());
+        assert_eq!("42", ValueBag::from(&sum).to_string());
+
+        let s = "a borrowed string";
+        let borrowed = || ValueBag::from(s);
+
+        assert_eq!(s, ValueBag::from_fn(&borrowed).to_string());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn fill_cast() {
@@ -162,6 +276,38 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fill_sampled() {
+        let fill = |slot: Slot| slot.fill_any(42u64);
+
+        for _ in 0..100 {
+            assert!(ValueBag::from_fill_sampled_with(0.0, || 0.0, &fill).is_empty());
+            assert_eq!(
+                Some(42),
+                ValueBag::from_fill_sampled_with(1.0, || 0.99, &fill).to_u64()
+            );
+        }
+
+        assert_eq!(
+            Some(42),
+            ValueBag::from_fill_sampled_with(0.5, || 0.3, &fill).to_u64()
+        );
+        assert!(ValueBag::from_fill_sampled_with(0.5, || 0.7, &fill).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fill_sampled_random() {
+        let fill = |slot: Slot| slot.fill_any(42u64);
+
+        for _ in 0..100 {
+            assert!(ValueBag::from_fill_sampled(0.0, &fill).is_empty());
+            assert_eq!(Some(42), ValueBag::from_fill_sampled(1.0, &fill).to_u64());
+        }
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn fill_fn_borrowed() {
@@ -1,6 +1,22 @@
 //! Converting standard types into `ValueBag`s.
 
-use super::{Error, ValueBag};
+use super::{
+    std::{
+        cell::{Cell, RefCell},
+        cmp::{Ordering, Reverse},
+        marker::PhantomData,
+        num::{
+            NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+            NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+        },
+    },
+    Error, ValueBag,
+};
+
+#[cfg(value_bag_saturating)]
+use super::std::num::Saturating;
+
+use core::sync::atomic;
 
 macro_rules! convert_primitive {
     ($($t:ty: $from:ident, $to:ident,)*) => {
@@ -26,6 +42,37 @@ macro_rules! convert_primitive {
                 }
             }
 
+            impl<'v> From<Reverse<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: Reverse<$t>) -> Self {
+                    ValueBag::$from(v.0)
+                }
+            }
+
+            impl<'a, 'v> From<&'a Reverse<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a Reverse<$t>) -> Self {
+                    ValueBag::$from(v.0)
+                }
+            }
+
+            impl<'a, 'v> From<&'a Cell<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a Cell<$t>) -> Self {
+                    ValueBag::$from(v.get())
+                }
+            }
+
+            impl<'a, 'v> From<&'a RefCell<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a RefCell<$t>) -> Self {
+                    match v.try_borrow() {
+                        Ok(v) => ValueBag::$from(*v),
+                        Err(_) => ValueBag::empty(),
+                    }
+                }
+            }
+
             impl<'v> TryFrom<ValueBag<'v>> for $t {
                 type Error = Error;
 
@@ -55,6 +102,58 @@ impl<'a, 'v> From<&'a ()> for ValueBag<'v> {
     }
 }
 
+/// Both `Some(())` and `None` are captured as the empty value.
+///
+/// The unit type carries no data, so an optional unit is equivalent to an explicit null.
+impl<'v> From<Option<()>> for ValueBag<'v> {
+    #[inline]
+    fn from(_: Option<()>) -> Self {
+        ValueBag::empty()
+    }
+}
+
+impl<'a, 'v> From<&'a Option<()>> for ValueBag<'v> {
+    #[inline]
+    fn from(_: &'a Option<()>) -> Self {
+        ValueBag::empty()
+    }
+}
+
+impl<'v, T: ?Sized> From<PhantomData<T>> for ValueBag<'v> {
+    #[inline]
+    fn from(_: PhantomData<T>) -> Self {
+        ValueBag::empty()
+    }
+}
+
+impl<'a, 'v, T: ?Sized> From<&'a PhantomData<T>> for ValueBag<'v> {
+    #[inline]
+    fn from(_: &'a PhantomData<T>) -> Self {
+        ValueBag::empty()
+    }
+}
+
+impl<'v> From<Ordering> for ValueBag<'v> {
+    #[inline]
+    fn from(v: Ordering) -> Self {
+        ValueBag::from_ordering(v)
+    }
+}
+
+impl<'a, 'v> From<&'a Ordering> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'a Ordering) -> Self {
+        ValueBag::from_ordering(*v)
+    }
+}
+
+impl<'a, 'v> From<&'a ValueBag<'v>> for ValueBag<'a> {
+    #[inline]
+    fn from(v: &'a ValueBag<'v>) -> Self {
+        v.by_ref()
+    }
+}
+
 convert_primitive!(
     u8: from_u8, to_u64,
     u16: from_u16, to_u64,
@@ -71,6 +170,134 @@ convert_primitive!(
     char: from_char, to_char,
 );
 
+macro_rules! convert_wrapping {
+    ($($t:ty: $from:ident,)*) => {
+        $(
+            impl<'v> From<Wrapping<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: Wrapping<$t>) -> Self {
+                    ValueBag::$from(v.0)
+                }
+            }
+
+            impl<'a, 'v> From<&'a Wrapping<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a Wrapping<$t>) -> Self {
+                    ValueBag::$from(v.0)
+                }
+            }
+
+            #[cfg(value_bag_saturating)]
+            impl<'v> From<Saturating<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: Saturating<$t>) -> Self {
+                    ValueBag::$from(v.0)
+                }
+            }
+
+            #[cfg(value_bag_saturating)]
+            impl<'a, 'v> From<&'a Saturating<$t>> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a Saturating<$t>) -> Self {
+                    ValueBag::$from(v.0)
+                }
+            }
+        )*
+    };
+}
+
+convert_wrapping!(
+    u8: from_u8,
+    u16: from_u16,
+    u32: from_u32,
+    u64: from_u64,
+    usize: from_usize,
+    i8: from_i8,
+    i16: from_i16,
+    i32: from_i32,
+    i64: from_i64,
+    isize: from_isize,
+);
+
+macro_rules! convert_non_zero {
+    ($($t:ty: $from:ident,)*) => {
+        $(
+            impl<'v> From<$t> for ValueBag<'v> {
+                #[inline]
+                fn from(v: $t) -> Self {
+                    ValueBag::$from(v.get())
+                }
+            }
+
+            impl<'a, 'v> From<&'a $t> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a $t) -> Self {
+                    ValueBag::$from(v.get())
+                }
+            }
+        )*
+    };
+}
+
+convert_non_zero!(
+    NonZeroU8: from_u8,
+    NonZeroU16: from_u16,
+    NonZeroU32: from_u32,
+    NonZeroU64: from_u64,
+    NonZeroUsize: from_usize,
+    NonZeroI8: from_i8,
+    NonZeroI16: from_i16,
+    NonZeroI32: from_i32,
+    NonZeroI64: from_i64,
+    NonZeroIsize: from_isize,
+);
+
+/*
+Atomics are captured as a snapshot of their current value, loaded using
+`Ordering::Relaxed`. The snapshot isn't synchronized with other memory,
+so it's suitable for independent values like counters, but not for values
+used to guard access to other data.
+*/
+
+macro_rules! convert_atomic {
+    ($($(#[$cfg:meta])* $t:ident: $from:ident,)*) => {
+        $(
+            $(#[$cfg])*
+            impl<'a, 'v> From<&'a atomic::$t> for ValueBag<'v> {
+                #[inline]
+                fn from(v: &'a atomic::$t) -> Self {
+                    ValueBag::$from(v.load(atomic::Ordering::Relaxed))
+                }
+            }
+        )*
+    };
+}
+
+convert_atomic!(
+    #[cfg(target_has_atomic = "8")]
+    AtomicU8: from_u8,
+    #[cfg(target_has_atomic = "16")]
+    AtomicU16: from_u16,
+    #[cfg(target_has_atomic = "32")]
+    AtomicU32: from_u32,
+    #[cfg(target_has_atomic = "64")]
+    AtomicU64: from_u64,
+    #[cfg(target_has_atomic = "ptr")]
+    AtomicUsize: from_usize,
+    #[cfg(target_has_atomic = "8")]
+    AtomicI8: from_i8,
+    #[cfg(target_has_atomic = "16")]
+    AtomicI16: from_i16,
+    #[cfg(target_has_atomic = "32")]
+    AtomicI32: from_i32,
+    #[cfg(target_has_atomic = "64")]
+    AtomicI64: from_i64,
+    #[cfg(target_has_atomic = "ptr")]
+    AtomicIsize: from_isize,
+    #[cfg(target_has_atomic = "8")]
+    AtomicBool: from_bool,
+);
+
 impl<'v> From<f32> for ValueBag<'v> {
     #[inline]
     fn from(v: f32) -> Self {
@@ -192,11 +419,83 @@ where
     }
 }
 
+/*
+Elements of generic containers, like tuples and maps, are limited to a fixed
+set of types, rather than anything where `&T: Into<ValueBag>`.
+
+A conversion like `From<&'v BTreeMap<K, V>>` bounded by `&'v K: Into<ValueBag<'v>>`
+is recursive, because checking the bound means looking through every `From<&T>`
+impl, including the map conversion itself. Whenever the argument to `ValueBag::from`
+isn't fully inferred yet, like in `ValueBag::from(a + b)`, the compiler follows that
+recursion until it overflows (E0275), so existing callers would stop compiling.
+Methods like `ValueBag::from_entries` can use `Into` bounds because they're not
+candidates for `From`.
+
+Since `Element` borrows its result from `&self`, containers of elements can also
+implement `Seq` and `Map` directly, without needing a wrapper to line up lifetimes.
+*/
+
+#[cfg(any(feature = "seq", feature = "map"))]
+pub(crate) trait Element {
+    fn to_value_bag(&self) -> ValueBag<'_>;
+}
+
+#[cfg(any(feature = "seq", feature = "map"))]
+macro_rules! element(
+    ($($t:ty,)*) => {
+        $(
+            impl Element for $t {
+                fn to_value_bag(&self) -> ValueBag<'_> {
+                    self.into()
+                }
+            }
+        )*
+    }
+);
+
+#[cfg(any(feature = "seq", feature = "map"))]
+element![
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    bool,
+    char,
+    (),
+];
+
+#[cfg(any(feature = "seq", feature = "map"))]
+impl Element for &str {
+    fn to_value_bag(&self) -> ValueBag<'_> {
+        self.into()
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod alloc_support {
     use super::*;
 
-    use crate::std::{borrow::Cow, string::String};
+    #[cfg(any(feature = "seq", feature = "map"))]
+    impl Element for String {
+        fn to_value_bag(&self) -> ValueBag<'_> {
+            self.into()
+        }
+    }
+
+    use crate::std::{borrow::Cow, ffi::CStr, rc::Rc, string::String};
+
+    #[cfg(target_has_atomic = "ptr")]
+    use crate::std::sync::Arc;
 
     impl<'v> From<&'v String> for ValueBag<'v> {
         #[inline]
@@ -223,6 +522,13 @@ mod alloc_support {
         }
     }
 
+    impl<'v> From<Option<&'v Cow<'v, str>>> for ValueBag<'v> {
+        #[inline]
+        fn from(v: Option<&'v Cow<'v, str>>) -> Self {
+            ValueBag::from_option(v)
+        }
+    }
+
     impl<'v> TryFrom<ValueBag<'v>> for Cow<'v, str> {
         type Error = Error;
 
@@ -231,6 +537,105 @@ mod alloc_support {
             v.to_str().ok_or_else(|| Error::msg("conversion failed"))
         }
     }
+
+    #[cfg(target_has_atomic = "ptr")]
+    impl<'v> From<&'v Arc<str>> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v Arc<str>) -> Self {
+            #[cfg(feature = "owned")]
+            {
+                ValueBag {
+                    inner: crate::internal::Internal::SharedRefStr(v),
+                }
+            }
+            #[cfg(not(feature = "owned"))]
+            {
+                ValueBag::from_str(v)
+            }
+        }
+    }
+
+    impl<'v> From<&'v Rc<str>> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v Rc<str>) -> Self {
+            ValueBag::from_str(v)
+        }
+    }
+
+    impl<'v> From<&'v Cow<'v, [u8]>> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v Cow<'v, [u8]>) -> Self {
+            ValueBag::from_bytes(v)
+        }
+    }
+
+    impl<'v> TryFrom<ValueBag<'v>> for Cow<'v, [u8]> {
+        type Error = Error;
+
+        #[inline]
+        fn try_from(v: ValueBag<'v>) -> Result<Self, Error> {
+            v.to_bytes().ok_or_else(|| Error::msg("conversion failed"))
+        }
+    }
+
+    // C strings are captured as strings when they're valid UTF8,
+    // and are lossily converted into strings when they're not
+    impl<'v> From<&'v CStr> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v CStr) -> Self {
+            match v.to_str() {
+                Ok(v) => ValueBag::from_str(v),
+                Err(_) => ValueBag {
+                    inner: crate::internal::Internal::LossyStr(v.to_bytes()),
+                },
+            }
+        }
+    }
+
+    impl<'v, 'u> From<&'v &'u CStr> for ValueBag<'v>
+    where
+        'u: 'v,
+    {
+        #[inline]
+        fn from(v: &'v &'u CStr) -> Self {
+            ValueBag::from(*v)
+        }
+    }
+}
+
+#[cfg(value_bag_unstable_ascii_char)]
+mod ascii_char_support {
+    use super::*;
+
+    use crate::std::ascii::Char;
+
+    impl<'v> From<&'v Char> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v Char) -> Self {
+            ValueBag::from_str(v.as_str())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+
+    use crate::std::{ffi::CString, thread::ThreadId};
+
+    impl<'v> From<&'v ThreadId> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v ThreadId) -> Self {
+            ValueBag::capture_debug(v)
+        }
+    }
+
+    impl<'v> From<&'v CString> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v CString) -> Self {
+            ValueBag::from(v.as_c_str())
+        }
+    }
 }
 
 #[cfg(feature = "owned")]
@@ -316,4 +721,248 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_into_structured_detailed() {
+        use crate::ValueBag;
+
+        assert_eq!(
+            "a borrowed string"
+                .into_value_bag()
+                .by_ref()
+                .to_test_token_detailed(),
+            TestToken::BorrowedStr("a borrowed string".to_owned())
+        );
+        assert_eq!(
+            ValueBag::from_display(&"a formatted string").to_test_token_detailed(),
+            TestToken::Str("a formatted string".to_owned())
+        );
+        assert_eq!(
+            "a borrowed string"
+                .into_value_bag()
+                .by_ref()
+                .to_test_token(),
+            TestToken::Str("a borrowed string".to_owned())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_shared_str() {
+        use crate::{
+            std::{rc::Rc, sync::Arc},
+            ValueBag,
+        };
+
+        let arc: Arc<str> = Arc::from("a shared string");
+        let rc: Rc<str> = Rc::from("a shared string");
+
+        assert_eq!(
+            Some("a shared string"),
+            ValueBag::from(&arc).to_borrowed_str()
+        );
+        assert_eq!(
+            Some("a shared string"),
+            ValueBag::from(&rc).to_borrowed_str()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_option_cow_str() {
+        use crate::{std::borrow::Cow, ValueBag};
+
+        let borrowed = Cow::Borrowed("x");
+        let owned = Cow::<str>::Owned("x".into());
+
+        assert_eq!(Some("x"), ValueBag::from(Some(&borrowed)).to_borrowed_str());
+        assert_eq!(Some("x"), ValueBag::from(Some(&owned)).to_borrowed_str());
+        assert!(ValueBag::from(None::<&Cow<str>>).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    // `c""` literals aren't available on all the compilers we test with
+    #[allow(clippy::manual_c_str_literals)]
+    fn test_c_str() {
+        use crate::{
+            std::ffi::{CStr, CString},
+            ValueBag,
+        };
+
+        let c_str = CStr::from_bytes_with_nul(b"a C string\0").unwrap();
+        assert_eq!(Some("a C string"), ValueBag::from(c_str).to_borrowed_str());
+        assert_eq!(Some("a C string"), ValueBag::from(&c_str).to_borrowed_str());
+
+        let c_string = CString::new("an owned C string").unwrap();
+        assert_eq!(
+            "an owned C string",
+            ValueBag::from(&c_string).to_str().unwrap()
+        );
+
+        let lossy = CString::new(vec![b'a', 0xff, b'b']).unwrap();
+        assert_eq!("a\u{fffd}b", ValueBag::from(&lossy).to_str().unwrap());
+        assert_eq!(None, ValueBag::from(&lossy).to_borrowed_str());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_thread_id() {
+        use crate::{std::thread, ValueBag};
+
+        let id = thread::current().id();
+        let value = ValueBag::from(&id);
+
+        assert_eq!(format!("{:?}", id), format!("{:?}", value));
+        assert_eq!(Some(&id), value.downcast_ref::<thread::ThreadId>());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_reverse() {
+        use crate::{std::cmp::Reverse, ValueBag};
+
+        assert_eq!(Some(5), ValueBag::from(Reverse(5u64)).to_u64());
+        assert_eq!(Some(-5), ValueBag::from(&Reverse(-5i8)).to_i64());
+        assert_eq!(Some('a'), ValueBag::from(Reverse('a')).to_char());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_ordering() {
+        use crate::{std::cmp::Ordering, ValueBag};
+
+        assert_eq!(Some(-1), ValueBag::from(Ordering::Less).to_i64());
+        assert_eq!(Some(0), ValueBag::from(Ordering::Equal).to_i64());
+        assert_eq!(Some(1), ValueBag::from(&Ordering::Greater).to_i64());
+
+        assert_eq!("Less", ValueBag::from(Ordering::Less).to_string());
+        assert_eq!("Equal", ValueBag::from(Ordering::Equal).to_string());
+        assert_eq!(
+            "Greater",
+            format!("{:?}", ValueBag::from(Ordering::Greater))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_cell() {
+        use crate::{std::cell::Cell, ValueBag};
+
+        let cell = Cell::new(1u64);
+        let value = ValueBag::from(&cell);
+
+        cell.set(2);
+
+        assert_eq!(Some(1), value.to_u64());
+        assert_eq!(Some(2), ValueBag::from(&cell).to_u64());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_ref_cell() {
+        use crate::{std::cell::RefCell, ValueBag};
+
+        let cell = RefCell::new(1u64);
+        assert_eq!(Some(1), ValueBag::from(&cell).to_u64());
+
+        let borrowed = cell.borrow();
+        assert_eq!(Some(1), ValueBag::from(&cell).to_u64());
+        drop(borrowed);
+
+        let borrowed = cell.borrow_mut();
+        assert!(ValueBag::from(&cell).is_empty());
+        drop(borrowed);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_wrapping() {
+        use crate::{std::num::Wrapping, ValueBag};
+
+        assert_eq!(Some(5), ValueBag::from(Wrapping(5u64)).to_u64());
+        assert_eq!(Some(-5), ValueBag::from(&Wrapping(-5i8)).to_i64());
+    }
+
+    #[test]
+    #[cfg(value_bag_saturating)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_saturating() {
+        use crate::{std::num::Saturating, ValueBag};
+
+        assert_eq!(Some(5), ValueBag::from(Saturating(5u64)).to_u64());
+        assert_eq!(Some(-5), ValueBag::from(&Saturating(-5i8)).to_i64());
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_atomic() {
+        use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        use crate::ValueBag;
+
+        let counter = AtomicUsize::new(1);
+        let snapshot = ValueBag::from(&counter);
+
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        assert_eq!(Some(1), snapshot.to_u64());
+        assert_eq!(Some(2), ValueBag::from(&counter).to_u64());
+        assert_eq!(Some(true), ValueBag::from(&AtomicBool::new(true)).to_bool());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_non_zero() {
+        use crate::{
+            std::num::{NonZeroI8, NonZeroU64},
+            ValueBag,
+        };
+
+        assert_eq!(
+            Some(5),
+            ValueBag::from(NonZeroU64::new(5).unwrap()).to_u64()
+        );
+        assert_eq!(
+            Some(-5),
+            ValueBag::from(&NonZeroI8::new(-5).unwrap()).to_i64()
+        );
+    }
+
+    #[test]
+    #[cfg(value_bag_unstable_ascii_char)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_ascii_char() {
+        use crate::{std::ascii::Char, ValueBag};
+
+        let c = Char::from_u8(b'A').unwrap();
+
+        assert_eq!(Some("A"), ValueBag::from(&c).to_borrowed_str());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_phantom_data() {
+        use crate::{std::marker::PhantomData, ValueBag};
+
+        assert!(ValueBag::from(PhantomData::<u8>).is_empty());
+        assert!(ValueBag::from(&PhantomData::<str>).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_option_unit() {
+        use crate::ValueBag;
+
+        assert!(ValueBag::from(()).is_empty());
+        assert!(ValueBag::from(Some(())).is_empty());
+        assert!(ValueBag::from(None::<()>).is_empty());
+        assert!(ValueBag::from(&Some(())).is_empty());
+    }
 }
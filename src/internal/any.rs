@@ -0,0 +1,197 @@
+//! Capturing `dyn Any` values using a registry of known types.
+//!
+//! Well-known primitives are always supported. Other types can
+//! be registered at runtime along with a function to capture them.
+
+use crate::{
+    std::{
+        any::{Any, TypeId},
+        collections::BTreeMap,
+        sync::{Arc, RwLock},
+    },
+    ValueBag,
+};
+
+type Capture = Arc<dyn for<'a> Fn(&'a dyn Any) -> Option<ValueBag<'a>> + Send + Sync>;
+
+static CAPTURES: RwLock<BTreeMap<TypeId, Capture>> = RwLock::new(BTreeMap::new());
+
+type CapturePrimitiveFn = for<'a> unsafe fn(&'a ()) -> ValueBag<'a>;
+
+static PRIMITIVES: RwLock<BTreeMap<TypeId, CapturePrimitiveFn>> = RwLock::new(BTreeMap::new());
+
+/// A type that can be captured as a primitive.
+///
+/// Types that implement this trait and are registered using [`ValueBag::register_primitive`]
+/// can be captured by [`ValueBag::try_capture_registered`] without knowing their concrete type.
+pub trait CapturePrimitive: 'static {
+    /// Get the primitive value for this type.
+    fn capture_primitive(&self) -> ValueBag<'_>;
+}
+
+impl<'v> ValueBag<'v> {
+    /// Register a function to capture values of type `T` through [`ValueBag::from_any_dyn`].
+    ///
+    /// Registering a function for a type that's already registered will replace it.
+    pub fn register_any_dyn<T, F>(capture: F)
+    where
+        T: 'static,
+        F: for<'a> Fn(&'a T) -> ValueBag<'a> + Send + Sync + 'static,
+    {
+        let capture: Capture = Arc::new(move |value| value.downcast_ref::<T>().map(&capture));
+
+        CAPTURES
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(TypeId::of::<T>(), capture);
+    }
+
+    /// Try get a value from a `dyn Any`.
+    ///
+    /// This method will capture well-known primitives, along with any types registered
+    /// using [`ValueBag::register_any_dyn`]. If the type of the value isn't known then
+    /// this method will return `None`.
+    pub fn from_any_dyn(value: &'v dyn Any) -> Option<Self> {
+        if let Some(value) = crate::internal::cast::primitive::from_any_dyn(value) {
+            return Some(value);
+        }
+
+        // Release the lock before calling the capture function, in case it
+        // registers other types or captures values itself
+        let capture = CAPTURES
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(&value.type_id())?
+            .clone();
+
+        capture(value)
+    }
+
+    /// Try capture a raw value, including types registered using [`ValueBag::register_primitive`].
+    ///
+    /// This method is like [`ValueBag::try_capture`], but will also look for `T` in the
+    /// registry of primitive types if it isn't a well-known primitive. Other constructors,
+    /// like [`ValueBag::capture_debug`], don't check the registry.
+    pub fn try_capture_registered<T>(value: &'v T) -> Option<Self>
+    where
+        T: ?Sized + 'static,
+    {
+        ValueBag::try_capture(value).or_else(|| from_registered_primitive(value))
+    }
+
+    /// Register a type `T` to be captured as a primitive through [`ValueBag::try_capture_registered`].
+    pub fn register_primitive<T>()
+    where
+        T: CapturePrimitive,
+    {
+        unsafe fn capture<T: CapturePrimitive>(value: &()) -> ValueBag<'_> {
+            // SAFETY: The caller guarantees `value` points to a `T`
+            let value = unsafe { &*(value as *const () as *const T) };
+
+            value.capture_primitive()
+        }
+
+        PRIMITIVES
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(TypeId::of::<T>(), capture::<T>);
+    }
+}
+
+fn from_registered_primitive<T>(value: &T) -> Option<ValueBag<'_>>
+where
+    T: ?Sized + 'static,
+{
+    let capture = *PRIMITIVES
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .get(&TypeId::of::<T>())?;
+
+    // SAFETY: `capture` was registered for `T`, which is always sized,
+    // so casting `value` to a thin pointer retains its address
+    Some(unsafe { capture(&*(value as *const T as *const ())) })
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_any_dyn_primitive() {
+        assert_eq!(Some(1), ValueBag::from_any_dyn(&1u8).unwrap().to_u64());
+        assert_eq!(
+            Some("a string"),
+            ValueBag::from_any_dyn(&"a string")
+                .unwrap()
+                .to_borrowed_str()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_any_dyn_registered() {
+        struct Meters(u64);
+        struct Unregistered;
+
+        ValueBag::register_any_dyn(|v: &Meters| ValueBag::from(v.0));
+
+        let meters: &dyn Any = &Meters(42);
+        assert_eq!(Some(42), ValueBag::from_any_dyn(meters).unwrap().to_u64());
+
+        let unregistered: &dyn Any = &Unregistered;
+        assert!(ValueBag::from_any_dyn(unregistered).is_none());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_any_dyn_reentrant() {
+        struct Outer(Inner);
+        struct Inner(u64);
+
+        ValueBag::register_any_dyn(|v: &Outer| {
+            ValueBag::register_any_dyn(|v: &Inner| ValueBag::from(v.0));
+
+            ValueBag::from_any_dyn(&v.0).unwrap_or_else(ValueBag::empty)
+        });
+
+        let outer: &dyn Any = &Outer(Inner(42));
+        assert_eq!(Some(42), ValueBag::from_any_dyn(outer).unwrap().to_u64());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn try_capture_registered_primitive() {
+        #[derive(Debug)]
+        struct Id(u64);
+        struct Unregistered;
+
+        impl CapturePrimitive for Id {
+            fn capture_primitive(&self) -> ValueBag<'_> {
+                ValueBag::from(self.0)
+            }
+        }
+
+        assert!(ValueBag::try_capture_registered(&Id(42)).is_none());
+
+        ValueBag::register_primitive::<Id>();
+
+        assert_eq!(
+            Some(42),
+            ValueBag::try_capture_registered(&Id(42)).unwrap().to_u64()
+        );
+        assert_eq!(
+            Some(1),
+            ValueBag::try_capture_registered(&1u8).unwrap().to_u64()
+        );
+
+        // Other constructors don't check the registry
+        assert!(ValueBag::try_capture(&Id(42)).is_none());
+        assert_eq!(None, ValueBag::capture_debug(&Id(42)).to_u64());
+
+        assert!(ValueBag::try_capture_registered(&Unregistered).is_none());
+    }
+}
@@ -0,0 +1,80 @@
+//! Integration between `Value` and base64 encoding from the `base64` crate.
+//!
+//! Bytes captured as base64 are encoded using the standard alphabet with padding
+//! whenever they're visited, so they're treated as strings everywhere. Formatting
+//! writes the encoding directly, other visitors receive an encoded string.
+
+use ::base64::{display::Base64Display, engine::general_purpose::STANDARD, Engine as _};
+
+use crate::{
+    fill::{Fill, Slot},
+    Error, ValueBag,
+};
+
+use super::Internal;
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a sequence of bytes that's encoded as base64.
+    ///
+    /// The bytes will be encoded when the value is visited, so conversions like
+    /// [`ValueBag::to_str`] and formatting using `Display` both produce the encoded
+    /// string rather than the bytes themselves.
+    pub const fn from_bytes_base64<B>(value: &'v B) -> Self
+    where
+        B: AsRef<[u8]>,
+    {
+        ValueBag {
+            inner: Internal::Fill(Base64Bytes::new_ref(value)),
+        }
+    }
+}
+
+#[repr(transparent)]
+struct Base64Bytes<B>(B);
+
+impl<B> Base64Bytes<B> {
+    const fn new_ref(v: &B) -> &Base64Bytes<B> {
+        // SAFETY: `Base64Bytes<B>` and `B` have the same ABI
+        unsafe { &*(v as *const B as *const Base64Bytes<B>) }
+    }
+}
+
+impl<B: AsRef<[u8]>> Fill for Base64Bytes<B> {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        let bytes = self.0.as_ref();
+
+        // Formatting writes the encoded bytes directly without allocating
+        slot.fill(|visitor| {
+            visitor.args_with_fill(
+                &format_args!("{}", Base64Display::new(bytes, &STANDARD)),
+                &|slot: Slot| slot.fill(|visitor| visitor.str(&STANDARD.encode(bytes))),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    use crate::std::{format, string::ToString};
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn base64_capture() {
+        let value = ValueBag::from_bytes_base64(b"value-bag");
+
+        assert_eq!("dmFsdWUtYmFn", value.to_string());
+        assert_eq!("dmFsdWUtYmFn", format!("{:?}", value));
+        assert_eq!("dmFsdWUtYmFn", value.to_str().unwrap());
+        assert_eq!(None, value.to_borrowed_bytes());
+
+        assert_eq!(
+            "3q0=",
+            ValueBag::from_bytes_base64(&[0xde, 0xad]).to_string()
+        );
+    }
+}
@@ -4,15 +4,18 @@
 //! but may end up executing arbitrary caller code if the value is complex.
 //! They will also attempt to downcast erased types into a primitive where possible.
 
-use crate::std::fmt;
+use crate::std::{
+    any::{Any, TypeId},
+    fmt, mem,
+};
 
 #[cfg(feature = "alloc")]
-use crate::std::{borrow::ToOwned, string::String};
+use crate::std::{borrow::ToOwned, string::String, vec::Vec};
 
 use super::{Internal, InternalVisitor};
 use crate::{Error, ValueBag};
 
-mod primitive;
+pub(super) mod primitive;
 
 impl ValueBag<'static> {
     /// Try capture an owned raw value.
@@ -95,11 +98,25 @@ impl<'v> ValueBag<'v> {
     /// This method is like [`ValueBag::to_f64`] except will always return
     /// a `f64`, regardless of the actual type of underlying value. For
     /// numeric types, it will use a regular `as` conversion, which may be lossy.
-    /// For non-numeric types it will return `NaN`.
+    /// For non-numeric types it will return `NaN`. Since `NaN` is also a valid
+    /// `f64` that may have been captured directly, see [`ValueBag::as_finite_f64`]
+    /// for a variant that can distinguish these cases.
     pub fn as_f64(&self) -> f64 {
         self.inner.cast().as_f64()
     }
 
+    /// Get a finite `f64` from this value.
+    ///
+    /// This method is cheap for primitive types, but may call arbitrary
+    /// serialization implementations for complex ones.
+    ///
+    /// This method is like [`ValueBag::as_f64`] except will return `None`
+    /// instead of `NaN` or an infinity. That includes non-numeric types
+    /// as well as floating points that were captured as `NaN` or infinite.
+    pub fn as_finite_f64(&self) -> Option<f64> {
+        Some(self.as_f64()).filter(|v| v.is_finite())
+    }
+
     /// Try get a `bool` from this value.
     ///
     /// This method is cheap for primitive types, but may call arbitrary
@@ -124,53 +141,281 @@ impl<'v> ValueBag<'v> {
         self.inner.cast().into_borrowed_str()
     }
 
+    /// Try get a byte buffer from this value.
+    ///
+    /// This method is cheap for primitive types. It won't allocate an owned
+    /// buffer if the value is a complex type.
+    pub fn to_borrowed_bytes(&self) -> Option<&'v [u8]> {
+        self.inner.cast().into_borrowed_bytes()
+    }
+
+    /// Try get the raw bytes of this value.
+    ///
+    /// This method will return the UTF-8 bytes of a borrowed string,
+    /// or the bytes of a borrowed byte buffer. Any other kind of value
+    /// will return `None`.
+    pub fn as_bytes(&self) -> Option<&'v [u8]> {
+        self.inner.cast().into_borrowed_raw_bytes()
+    }
+
     /// Check whether this value is empty.
     pub fn is_empty(&self) -> bool {
         matches!(self.inner, Internal::None)
     }
 
+    /// Get the length of this value if it's a string or a sequence.
+    ///
+    /// Strings return their length in bytes. Sequences return their number of elements,
+    /// which are counted by visiting them. Any other kind of value will return `None`.
+    ///
+    /// This method isn't related to [`ValueBag::is_empty`], which checks whether
+    /// the value is missing rather than whether it has any elements.
+    pub fn len(&self) -> Option<usize> {
+        match self.inner.cast() {
+            Cast::Str(value) => return Some(value.len()),
+            #[cfg(feature = "alloc")]
+            Cast::String(value) => return Some(value.len()),
+            _ => (),
+        }
+
+        #[cfg(feature = "seq")]
+        {
+            self.count_seq()
+        }
+        #[cfg(not(feature = "seq"))]
+        {
+            None
+        }
+    }
+
+    /// Get a static label for the kind of value this is.
+    ///
+    /// The label is intended for diagnostics, like `"u64"`, `"str"`, `"debug"`, or `"seq"`.
+    /// Values captured using [`ValueBag::from_fill`] will be filled to determine their kind.
+    pub fn kind_name(&self) -> &'static str {
+        struct KindVisitor(&'static str);
+
+        impl<'v> InternalVisitor<'v> for KindVisitor {
+            fn fill(&mut self, v: &dyn crate::fill::Fill) -> Result<(), Error> {
+                v.fill(crate::fill::Slot::new(self))
+            }
+
+            fn debug(&mut self, _: &dyn fmt::Debug) -> Result<(), Error> {
+                self.0 = "debug";
+                Ok(())
+            }
+
+            fn display(&mut self, _: &dyn fmt::Display) -> Result<(), Error> {
+                self.0 = "display";
+                Ok(())
+            }
+
+            fn u64(&mut self, _: u64) -> Result<(), Error> {
+                self.0 = "u64";
+                Ok(())
+            }
+
+            fn i64(&mut self, _: i64) -> Result<(), Error> {
+                self.0 = "i64";
+                Ok(())
+            }
+
+            fn u128(&mut self, _: &u128) -> Result<(), Error> {
+                self.0 = "u128";
+                Ok(())
+            }
+
+            fn i128(&mut self, _: &i128) -> Result<(), Error> {
+                self.0 = "i128";
+                Ok(())
+            }
+
+            fn f64(&mut self, _: f64) -> Result<(), Error> {
+                self.0 = "f64";
+                Ok(())
+            }
+
+            fn bool(&mut self, _: bool) -> Result<(), Error> {
+                self.0 = "bool";
+                Ok(())
+            }
+
+            fn char(&mut self, _: char) -> Result<(), Error> {
+                self.0 = "char";
+                Ok(())
+            }
+
+            fn str(&mut self, _: &str) -> Result<(), Error> {
+                self.0 = "str";
+                Ok(())
+            }
+
+            fn bytes(&mut self, _: &[u8]) -> Result<(), Error> {
+                self.0 = "bytes";
+                Ok(())
+            }
+
+            fn none(&mut self) -> Result<(), Error> {
+                self.0 = "none";
+                Ok(())
+            }
+
+            #[cfg(feature = "error")]
+            fn error(&mut self, _: &dyn super::error::Error) -> Result<(), Error> {
+                self.0 = "error";
+                Ok(())
+            }
+
+            #[cfg(feature = "sval2")]
+            fn sval2(&mut self, _: &dyn super::sval::v2::Value) -> Result<(), Error> {
+                self.0 = "sval2";
+                Ok(())
+            }
+
+            #[cfg(feature = "serde1")]
+            fn serde1(&mut self, _: &dyn super::serde::v1::Serialize) -> Result<(), Error> {
+                self.0 = "serde1";
+                Ok(())
+            }
+
+            #[cfg(feature = "seq")]
+            fn seq(&mut self, _: &dyn super::seq::Seq) -> Result<(), Error> {
+                self.0 = "seq";
+                Ok(())
+            }
+
+            #[cfg(feature = "map")]
+            fn map(&mut self, _: &dyn super::map::Map) -> Result<(), Error> {
+                self.0 = "map";
+                Ok(())
+            }
+
+            fn poisoned(&mut self, _: &'static str) -> Result<(), Error> {
+                self.0 = "poisoned";
+                Ok(())
+            }
+        }
+
+        let mut visitor = KindVisitor("none");
+        let _ = self.internal_visit(&mut visitor);
+
+        visitor.0
+    }
+
     /// Check whether this value can be downcast to `T`.
-    pub fn is<T: 'static>(&self) -> bool {
+    pub fn is<T: ?Sized + 'static>(&self) -> bool {
         self.downcast_ref::<T>().is_some()
     }
 
     /// Try downcast this value to `T`.
-    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
-        match self.inner {
-            Internal::Debug(value) => value.as_any().downcast_ref(),
-            Internal::Display(value) => value.as_any().downcast_ref(),
+    ///
+    /// Primitives can be downcast to the type they're stored as, which is one of
+    /// `u64`, `i64`, `u128`, `i128`, `f64`, `bool`, or `char`. Smaller numbers,
+    /// like a `u8`, are widened when they're converted using `From`, so they need
+    /// to be downcast to their wider type. Primitives captured using one of the
+    /// `capture_*` methods keep their original type. Strings captured as primitives
+    /// can be downcast to `str`.
+    pub fn downcast_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        if TypeId::of::<T>() == TypeId::of::<str>() {
+            let value: &str = match self.inner {
+                Internal::Str(value) => value,
+                #[cfg(feature = "owned")]
+                Internal::SharedRefStr(value) => value,
+                _ => return None,
+            };
+
+            // SAFETY: `T` is `str`, so `&str` and `&T` are the same type
+            return Some(unsafe { mem::transmute_copy::<&str, &T>(&value) });
+        }
+
+        let value = self
+            .inner
+            .as_any()
+            .or_else(|| self.inner.as_primitive_any())?;
+
+        if <dyn Any>::type_id(value) == TypeId::of::<T>() {
+            let value = value as *const dyn Any as *const ();
+
+            // SAFETY: `T` is the type of the captured value, which is always `Sized`,
+            // so `&T` is a thin pointer to the same data as `value`
+            Some(unsafe { mem::transmute_copy::<*const (), &T>(&value) })
+        } else {
+            None
+        }
+    }
+
+    /// Get the `TypeId` of the type this value was captured from.
+    ///
+    /// This method will return `Some` for values captured using one of the
+    /// `capture_*` methods, and `None` for primitives and anonymous values.
+    pub(crate) fn type_id(&self) -> Option<TypeId> {
+        self.inner.as_any().map(<dyn Any>::type_id)
+    }
+}
+
+impl<'v> Internal<'v> {
+    /// Get the inner value as an `Any` if it's a primitive.
+    #[inline]
+    fn as_primitive_any(&self) -> Option<&dyn Any> {
+        match self {
+            Internal::Signed(value) => Some(value),
+            Internal::Unsigned(value) => Some(value),
+            #[cfg(not(feature = "inline-i128"))]
+            Internal::BigSigned(value) => Some(*value),
+            #[cfg(feature = "inline-i128")]
+            Internal::BigSigned(value) => Some(value),
+            #[cfg(not(feature = "inline-i128"))]
+            Internal::BigUnsigned(value) => Some(*value),
+            #[cfg(feature = "inline-i128")]
+            Internal::BigUnsigned(value) => Some(value),
+            Internal::Float(value) => Some(value),
+            Internal::Bool(value) => Some(value),
+            Internal::Char(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the inner value as an `Any` if it was captured with its type.
+    #[inline]
+    fn as_any(&self) -> Option<&dyn Any> {
+        match self {
+            Internal::Primitive(value) => Some(value.as_any()),
+            Internal::Debug(value) => Some(value.as_any()),
+            Internal::Display(value) => Some(value.as_any()),
             #[cfg(feature = "error")]
-            Internal::Error(value) => value.as_any().downcast_ref(),
+            Internal::Error(value) => Some(value.as_any()),
             #[cfg(feature = "sval2")]
-            Internal::Sval2(value) => value.as_any().downcast_ref(),
+            Internal::Sval2(value) => Some(value.as_any()),
             #[cfg(feature = "serde1")]
-            Internal::Serde1(value) => value.as_any().downcast_ref(),
+            Internal::Serde1(value) => Some(value.as_any()),
 
             #[cfg(feature = "owned")]
-            Internal::SharedDebug(ref value) => value.as_any().downcast_ref(),
+            Internal::SharedDebug(value) => Some(value.as_any()),
             #[cfg(feature = "owned")]
-            Internal::SharedDisplay(ref value) => value.as_any().downcast_ref(),
+            Internal::SharedDisplay(value) => Some(value.as_any()),
             #[cfg(all(feature = "error", feature = "owned"))]
-            Internal::SharedError(ref value) => value.as_any().downcast_ref(),
+            Internal::SharedError(value) => Some(value.as_any()),
             #[cfg(all(feature = "serde1", feature = "owned"))]
-            Internal::SharedSerde1(ref value) => value.as_any().downcast_ref(),
+            Internal::SharedSerde1(value) => Some(value.as_any()),
             #[cfg(all(feature = "sval2", feature = "owned"))]
-            Internal::SharedSval2(ref value) => value.as_any().downcast_ref(),
+            Internal::SharedSval2(value) => Some(value.as_any()),
             #[cfg(all(feature = "seq", feature = "owned"))]
-            Internal::SharedSeq(ref value) => value.as_any().downcast_ref(),
+            Internal::SharedSeq(value) => Some(value.as_any()),
 
             #[cfg(feature = "owned")]
-            Internal::SharedRefDebug(value) => value.as_any().downcast_ref(),
+            Internal::SharedRefDebug(value) => Some(value.as_any()),
             #[cfg(feature = "owned")]
-            Internal::SharedRefDisplay(value) => value.as_any().downcast_ref(),
+            Internal::SharedRefDisplay(value) => Some(value.as_any()),
             #[cfg(all(feature = "error", feature = "owned"))]
-            Internal::SharedRefError(value) => value.as_any().downcast_ref(),
+            Internal::SharedRefError(value) => Some(value.as_any()),
             #[cfg(all(feature = "serde1", feature = "owned"))]
-            Internal::SharedRefSerde1(value) => value.as_any().downcast_ref(),
+            Internal::SharedRefSerde1(value) => Some(value.as_any()),
             #[cfg(all(feature = "sval2", feature = "owned"))]
-            Internal::SharedRefSval2(value) => value.as_any().downcast_ref(),
+            Internal::SharedRefSval2(value) => Some(value.as_any()),
             #[cfg(all(feature = "seq", feature = "owned"))]
-            Internal::SharedRefSeq(value) => value.as_any().downcast_ref(),
+            Internal::SharedRefSeq(value) => Some(value.as_any()),
+            #[cfg(all(feature = "map", feature = "owned"))]
+            Internal::SharedRefMap(value) => Some(value.as_any()),
 
             _ => None,
         }
@@ -260,6 +505,25 @@ impl<'v> Internal<'v> {
                 Ok(())
             }
 
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                self.0 = Cast::ByteBuf(v.to_owned());
+                Ok(())
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            #[inline]
+            fn bytes(&mut self, _: &[u8]) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn borrowed_bytes(&mut self, v: &'v [u8]) -> Result<(), Error> {
+                self.0 = Cast::Bytes(v);
+                Ok(())
+            }
+
             #[inline]
             fn none(&mut self) -> Result<(), Error> {
                 self.0 = Cast::None;
@@ -307,6 +571,12 @@ impl<'v> Internal<'v> {
                 Ok(())
             }
 
+            #[cfg(feature = "map")]
+            fn map(&mut self, _: &dyn super::map::Map) -> Result<(), Error> {
+                self.0 = Cast::None;
+                Ok(())
+            }
+
             fn poisoned(&mut self, _: &'static str) -> Result<(), Error> {
                 self.0 = Cast::None;
                 Ok(())
@@ -328,7 +598,9 @@ impl<'v> Internal<'v> {
             Internal::Bool(value) => Cast::Bool(*value),
             Internal::Char(value) => Cast::Char(*value),
             Internal::Str(value) => Cast::Str(value),
+            Internal::Bytes(value) => Cast::Bytes(value),
             Internal::None => Cast::None,
+            Internal::Primitive(value) => (*value).to_internal().cast(),
             other => {
                 // If the erased value isn't a primitive then we visit it
                 let mut cast = CastVisitor(Cast::None);
@@ -348,9 +620,12 @@ pub(in crate::internal) enum Cast<'v> {
     Bool(bool),
     Char(char),
     Str(&'v str),
+    Bytes(&'v [u8]),
     None,
     #[cfg(feature = "alloc")]
     String(String),
+    #[cfg(feature = "alloc")]
+    ByteBuf(Vec<u8>),
 }
 
 impl<'v> Cast<'v> {
@@ -363,6 +638,24 @@ impl<'v> Cast<'v> {
         }
     }
 
+    #[inline]
+    fn into_borrowed_bytes(self) -> Option<&'v [u8]> {
+        if let Cast::Bytes(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn into_borrowed_raw_bytes(self) -> Option<&'v [u8]> {
+        match self {
+            Cast::Str(value) => Some(value.as_bytes()),
+            Cast::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn into_u64(self) -> Option<u64> {
         match self {
@@ -474,6 +767,16 @@ mod alloc_support {
         pub fn to_str(&self) -> Option<Cow<'v, str>> {
             self.inner.cast().into_str()
         }
+
+        /// Try get a byte buffer from this value.
+        ///
+        /// This method is cheap for primitive types, but may call arbitrary
+        /// serialization implementations for complex ones. If the serialization
+        /// implementation produces a short lived buffer it will be allocated.
+        #[inline]
+        pub fn to_bytes(&self) -> Option<Cow<'v, [u8]>> {
+            self.inner.cast().into_bytes()
+        }
     }
 
     impl<'v> Cast<'v> {
@@ -485,6 +788,15 @@ mod alloc_support {
                 _ => None,
             }
         }
+
+        #[inline]
+        pub(in crate::internal) fn into_bytes(self) -> Option<Cow<'v, [u8]>> {
+            match self {
+                Cast::Bytes(value) => Some(value.into()),
+                Cast::ByteBuf(value) => Some(value.into()),
+                _ => None,
+            }
+        }
     }
 
     #[cfg(test)]
@@ -492,7 +804,11 @@ mod alloc_support {
         #[cfg(target_arch = "wasm32")]
         use wasm_bindgen_test::*;
 
-        use crate::{std::borrow::ToOwned, test::IntoValueBag, ValueBag};
+        use crate::{
+            std::borrow::{Cow, ToOwned},
+            test::IntoValueBag,
+            ValueBag,
+        };
 
         #[test]
         #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
@@ -524,6 +840,127 @@ mod alloc_support {
                     .expect("invalid value")
             );
         }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn cow_bytes_cast() {
+            let borrowed = Cow::Borrowed(&[1u8, 2, 3][..]);
+            let owned = Cow::<[u8]>::Owned(vec![1u8, 2, 3]);
+
+            for bytes in [&borrowed, &owned] {
+                let value = ValueBag::from(bytes);
+
+                assert_eq!(&[1u8, 2, 3][..], &*value.to_bytes().expect("invalid value"));
+                assert_eq!(
+                    &[1u8, 2, 3][..],
+                    value.to_borrowed_bytes().expect("invalid value")
+                );
+            }
+
+            assert!(ValueBag::from("a string").to_bytes().is_none());
+        }
+    }
+}
+
+#[cfg(feature = "owned")]
+mod owned_support {
+    use super::*;
+
+    use crate::OwnedValueBag;
+
+    impl<'v> ValueBag<'v> {
+        /// Try add this value to another numeric value.
+        ///
+        /// Integers are added using checked arithmetic at the widest width of either value.
+        /// Floats can only be added to other floats.
+        ///
+        /// This method will return `None` if either value is not a number, if the values
+        /// can't be added to each other, or if the addition overflows.
+        pub fn try_add(&self, other: &ValueBag) -> Option<OwnedValueBag> {
+            match (self.inner.cast(), other.inner.cast()) {
+                (Cast::Float(a), Cast::Float(b)) => Some(ValueBag::from(a + b).to_owned()),
+                (Cast::Unsigned(a), Cast::Unsigned(b)) => {
+                    Some(ValueBag::from(a.checked_add(b)?).to_owned())
+                }
+                (Cast::Signed(a), Cast::Signed(b)) => {
+                    Some(ValueBag::from(a.checked_add(b)?).to_owned())
+                }
+                (
+                    a @ (Cast::Unsigned(_) | Cast::BigUnsigned(_)),
+                    b @ (Cast::Unsigned(_) | Cast::BigUnsigned(_)),
+                ) => {
+                    let sum = a.into_u128()?.checked_add(b.into_u128()?)?;
+
+                    Some(ValueBag::from(&sum).to_owned())
+                }
+                (
+                    a @ (Cast::Unsigned(_)
+                    | Cast::BigUnsigned(_)
+                    | Cast::Signed(_)
+                    | Cast::BigSigned(_)),
+                    b @ (Cast::Unsigned(_)
+                    | Cast::BigUnsigned(_)
+                    | Cast::Signed(_)
+                    | Cast::BigSigned(_)),
+                ) => {
+                    let sum = a.into_i128()?.checked_add(b.into_i128()?)?;
+
+                    Some(ValueBag::from(&sum).to_owned())
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::*;
+
+        use crate::ValueBag;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn try_add() {
+            assert_eq!(
+                Some(3),
+                ValueBag::from(1u64)
+                    .try_add(&ValueBag::from(2u64))
+                    .and_then(|sum| sum.by_ref().to_u64())
+            );
+            assert_eq!(
+                Some(-1),
+                ValueBag::from(1u64)
+                    .try_add(&ValueBag::from(-2i64))
+                    .and_then(|sum| sum.by_ref().to_i64())
+            );
+            assert_eq!(
+                Some(1.5),
+                ValueBag::from(1.0f64)
+                    .try_add(&ValueBag::from(0.5f64))
+                    .and_then(|sum| sum.by_ref().to_f64())
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn try_add_overflow() {
+            assert!(ValueBag::from(u64::MAX)
+                .try_add(&ValueBag::from(1u64))
+                .is_none());
+            assert!(ValueBag::from(i64::MIN)
+                .try_add(&ValueBag::from(-1i64))
+                .is_none());
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn try_add_non_numeric() {
+            assert!(ValueBag::from("1").try_add(&ValueBag::from(1u64)).is_none());
+            assert!(ValueBag::from(1.0f64)
+                .try_add(&ValueBag::from(1u64))
+                .is_none());
+        }
     }
 }
 
@@ -536,6 +973,31 @@ mod tests {
 
     use crate::test::IntoValueBag;
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn downcast_str() {
+        assert_eq!(Some("x"), ValueBag::from("x").downcast_ref::<str>());
+        assert!(ValueBag::from("x").is::<str>());
+
+        assert_eq!(None, ValueBag::from(1).downcast_ref::<str>());
+        assert_eq!(None, ValueBag::from_debug(&"x").downcast_ref::<str>());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn downcast_primitive() {
+        assert_eq!(Some(&5u64), ValueBag::from(5u64).downcast_ref::<u64>());
+        assert_eq!(Some(&5u64), ValueBag::from(5u8).downcast_ref::<u64>());
+        assert_eq!(None, ValueBag::from(5u8).downcast_ref::<u8>());
+        assert_eq!(None, ValueBag::from(5u64).downcast_ref::<i64>());
+
+        assert_eq!(Some(&true), ValueBag::from(true).downcast_ref::<bool>());
+        assert_eq!(Some(&'a'), ValueBag::from('a').downcast_ref::<char>());
+
+        assert_eq!(Some(&5u128), ValueBag::from(&5u128).downcast_ref::<u128>());
+        assert!(ValueBag::capture_debug(&5u64).is::<u64>());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn is_empty() {
@@ -544,6 +1006,44 @@ mod tests {
         assert!(ValueBag::try_capture(&None::<i32>).unwrap().is_empty(),);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn len() {
+        assert_eq!(Some(8), ValueBag::from("a string").len());
+        assert_eq!(Some(2), ValueBag::from("é").len());
+
+        assert_eq!(None, ValueBag::from(42u64).len());
+        assert_eq!(None, ValueBag::empty().len());
+    }
+
+    #[test]
+    #[cfg(feature = "seq")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn seq_len() {
+        assert_eq!(Some(3), ValueBag::from_seq_slice(&[1, 2, 3]).len());
+        assert_eq!(Some(0), ValueBag::from_seq_slice(&[] as &[u64; 0]).len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn kind_name() {
+        assert_eq!("u64", ValueBag::from(1u8).kind_name());
+        assert_eq!("i64", ValueBag::from(-1i8).kind_name());
+        assert_eq!("u128", ValueBag::from(&1u128).kind_name());
+        assert_eq!("f64", ValueBag::from(1.0f32).kind_name());
+        assert_eq!("bool", ValueBag::from(true).kind_name());
+        assert_eq!("char", ValueBag::from('a').kind_name());
+        assert_eq!("str", ValueBag::from("a").kind_name());
+        assert_eq!("bytes", ValueBag::from_bytes(&[1]).kind_name());
+        assert_eq!("none", ValueBag::empty().kind_name());
+        assert_eq!("debug", ValueBag::from_debug(&1).kind_name());
+        assert_eq!("display", ValueBag::from_display(&1).kind_name());
+        assert_eq!(
+            "u64",
+            ValueBag::from_fill(&|slot: crate::fill::Slot| slot.fill_any(1u8)).kind_name()
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn primitive_capture_str() {
@@ -696,6 +1196,17 @@ mod tests {
             .expect("invalid value"));
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn as_bytes_cast() {
+        assert_eq!(Some(&b"hi"[..]), ValueBag::from("hi").as_bytes());
+        assert_eq!(Some(&b"hi"[..]), ValueBag::from_bytes(b"hi").as_bytes());
+
+        assert_eq!(None, ValueBag::from(1u8).as_bytes());
+        assert_eq!(None, ValueBag::from('a').as_bytes());
+        assert_eq!(None, ValueBag::empty().as_bytes());
+    }
+
     #[test]
     fn as_cast() {
         assert_eq!(1.0, 1f64.into_value_bag().as_f64());
@@ -703,4 +1214,27 @@ mod tests {
         assert_eq!(-1.0, -(1i64.into_value_bag().as_f64()));
         assert!(true.into_value_bag().as_f64().is_nan());
     }
+
+    #[test]
+    fn as_finite_cast() {
+        assert_eq!(Some(1.0), 1f64.into_value_bag().as_finite_f64());
+        assert_eq!(Some(1.0), 1u64.into_value_bag().as_finite_f64());
+        assert_eq!(Some(-1.0), (-1i64).into_value_bag().as_finite_f64());
+
+        assert!(f64::NAN.into_value_bag().as_finite_f64().is_none());
+        assert!(f64::INFINITY.into_value_bag().as_finite_f64().is_none());
+        assert!(f64::NEG_INFINITY.into_value_bag().as_finite_f64().is_none());
+        assert!(true.into_value_bag().as_finite_f64().is_none());
+    }
+
+    #[cfg(feature = "seq")]
+    mod seq_support {
+        use super::*;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn kind_name_seq() {
+            assert_eq!("seq", ValueBag::from_seq_slice(&[1, 2, 3]).kind_name());
+        }
+    }
 }
@@ -6,14 +6,14 @@ a `Internal`.
 #[cfg(feature = "alloc")]
 use crate::std::string::String;
 
-use crate::ValueBag;
+use crate::{internal::Internal, ValueBag};
 
 // NOTE: The casts for unsized values (str) are dubious here. To really do this properly
 // we need https://github.com/rust-lang/rust/issues/81513
 // NOTE: With some kind of const `Any::is<T>` we could do all this at compile-time
 // Older versions of `value-bag` did this, but the infrastructure just wasn't worth
 // the tiny performance improvement
-use crate::std::any::TypeId;
+use crate::std::any::{Any, TypeId};
 
 enum Void {}
 
@@ -21,14 +21,14 @@ enum Void {}
 struct VoidRef<'a>(*const &'a Void);
 
 macro_rules! check_type_ids {
-    (&$l:lifetime $v:ident => $(
+    (&$l:lifetime $v:ident: $type_id:ident => $(
         $(#[cfg($($cfg:tt)*)])*
             $ty:ty,
         )*
     ) => {
         $(
             $(#[cfg($($cfg)*)])*
-            if TypeId::of::<T>() == TypeId::of::<$ty>() {
+            if $type_id == TypeId::of::<$ty>() {
                 // SAFETY: We verify the value is $ty before casting
                 let v = unsafe { *($v.0 as *const & $l $ty) };
 
@@ -37,7 +37,7 @@ macro_rules! check_type_ids {
         )*
         $(
             $(#[cfg($($cfg)*)])*
-            if TypeId::of::<T>() == TypeId::of::<Option<$ty>>() {
+            if $type_id == TypeId::of::<Option<$ty>>() {
                 // SAFETY: We verify the value is Option<$ty> before casting
                 let v = unsafe { *($v.0 as *const & $l Option<$ty>) };
 
@@ -51,44 +51,120 @@ macro_rules! check_type_ids {
     };
 }
 
-pub(in crate::internal) fn from_any<'v, T: ?Sized + 'static>(value: &'v T) -> Option<ValueBag<'v>> {
-    let type_ids = |v: VoidRef<'v>| {
-        if TypeId::of::<T>() == TypeId::of::<str>() {
-            // SAFETY: We verify the value is str before casting
-            let v = unsafe { *(v.0 as *const &'v str) };
+macro_rules! check_typed_type_ids {
+    (&$l:lifetime $v:ident: $type_id:ident => $($ty:ty,)*) => {
+        $(
+            if $type_id == TypeId::of::<$ty>() {
+                // SAFETY: We verify the value is $ty before casting
+                let v = unsafe { *($v.0 as *const & $l $ty) };
 
-            return Some(ValueBag::from(v));
-        }
+                return Some(ValueBag {
+                    inner: Internal::Primitive(v),
+                });
+            }
+        )*
+        $(
+            if $type_id == TypeId::of::<Option<$ty>>() {
+                // SAFETY: We verify the value is Option<$ty> before casting
+                let v = unsafe { *($v.0 as *const & $l Option<$ty>) };
 
-        check_type_ids!(
-            &'v v =>
-                usize,
-                u8,
-                u16,
-                u32,
-                u64,
-                u128,
-                isize,
-                i8,
-                i16,
-                i32,
-                i64,
-                i128,
-                f32,
-                f64,
-                char,
-                bool,
-                &'static str,
-                // We deal with `str` separately because it's unsized
-                // str,
-                #[cfg(feature = "alloc")]
-                String,
-        );
+                if let Some(v) = v {
+                    return Some(ValueBag::from(v));
+                } else {
+                    return Some(ValueBag::empty());
+                }
+            }
+        )*
+    };
+}
 
-        None
+/// A primitive that's been captured from its original type.
+///
+/// Primitives are widened when they're visited, but keep their original
+/// type so they can still be downcast to it.
+pub(crate) trait DowncastPrimitive {
+    fn as_any(&self) -> &dyn Any;
+    fn to_internal(&self) -> Internal<'_>;
+}
+
+macro_rules! impl_downcast_primitive {
+    ($($ty:ty,)*) => {
+        $(
+            impl DowncastPrimitive for $ty {
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn to_internal(&self) -> Internal<'_> {
+                    ValueBag::from(self).inner
+                }
+            }
+        )*
     };
+}
+
+impl_downcast_primitive!(
+    usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f32, f64, char, bool,
+);
+
+pub(in crate::internal) fn from_any<'v, T: ?Sized + 'static>(value: &'v T) -> Option<ValueBag<'v>> {
+    let v = VoidRef(&(value) as *const &'v T as *const &'v Void);
+
+    if TypeId::of::<T>() == TypeId::of::<str>() {
+        // SAFETY: We verify the value is str before casting
+        let v = unsafe { *(v.0 as *const &'v str) };
+
+        return Some(ValueBag::from(v));
+    }
 
-    (type_ids)(VoidRef(&(value) as *const &'v T as *const &'v Void))
+    from_sized_any(TypeId::of::<T>(), v)
+}
+
+#[cfg(feature = "std")]
+pub(in crate::internal) fn from_any_dyn<'v>(value: &'v dyn Any) -> Option<ValueBag<'v>> {
+    let type_id = value.type_id();
+
+    // `dyn Any` is always sized, so the data pointer is a thin reference to the value
+    let value = value as *const dyn Any as *const Void;
+
+    from_sized_any(
+        type_id,
+        VoidRef(&(value) as *const *const Void as *const &'v Void),
+    )
+}
+
+#[inline]
+fn from_sized_any<'v>(type_id: TypeId, v: VoidRef<'v>) -> Option<ValueBag<'v>> {
+    check_typed_type_ids!(
+        &'v v: type_id =>
+            usize,
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            isize,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            f32,
+            f64,
+            char,
+            bool,
+    );
+
+    check_type_ids!(
+        &'v v: type_id =>
+            &'static str,
+            // We deal with `str` separately because it's unsized
+            // str,
+            #[cfg(feature = "alloc")]
+            String,
+    );
+
+    None
 }
 
 #[cfg(feature = "owned")]
@@ -96,8 +172,10 @@ pub(in crate::internal) fn from_owned_any<'a, T: ?Sized + 'static>(
     value: &'a T,
 ) -> Option<ValueBag<'static>> {
     let type_ids = |v: VoidRef<'a>| {
+        let type_id = TypeId::of::<T>();
+
         check_type_ids!(
-            &'a v =>
+            &'a v: type_id =>
                 usize,
                 u8,
                 u16,
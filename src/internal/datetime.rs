@@ -0,0 +1,284 @@
+//! Integration between `Value` and timestamps from the `time` and `chrono` crates.
+//!
+//! Timestamps are captured as RFC3339 strings, so they're formatted consistently
+//! regardless of the framework used to serialize them.
+//!
+//! When the `time` feature is enabled, `std::time::SystemTime`s are also captured as RFC3339 strings.
+
+#[cfg(feature = "time")]
+mod time_support {
+    use ::time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+    use crate::{
+        fill::{Fill, Slot},
+        internal::Internal,
+        std::time::{SystemTime, UNIX_EPOCH},
+        Error, ValueBag,
+    };
+
+    impl<'v> ValueBag<'v> {
+        /// Get a value from a `time` timestamp.
+        ///
+        /// The timestamp will be captured as an RFC3339 string.
+        pub const fn from_offset_date_time(value: &'v OffsetDateTime) -> Self {
+            ValueBag {
+                inner: Internal::Fill(OffsetDateTimeRfc3339::new_ref(value)),
+            }
+        }
+
+        /// Try get a `time` timestamp from this value.
+        ///
+        /// This method will return `Some` if the value was captured from an `OffsetDateTime`,
+        /// or if it's an RFC3339 string.
+        pub fn to_offset_date_time(&self) -> Option<OffsetDateTime> {
+            if let Some(value) = self.downcast_ref::<OffsetDateTime>() {
+                return Some(*value);
+            }
+
+            OffsetDateTime::parse(&self.to_str()?, &Rfc3339).ok()
+        }
+    }
+
+    #[repr(transparent)]
+    struct OffsetDateTimeRfc3339(OffsetDateTime);
+
+    impl OffsetDateTimeRfc3339 {
+        const fn new_ref(v: &OffsetDateTime) -> &OffsetDateTimeRfc3339 {
+            // SAFETY: `OffsetDateTimeRfc3339` and `OffsetDateTime` have the same ABI
+            unsafe { &*(v as *const OffsetDateTime as *const OffsetDateTimeRfc3339) }
+        }
+    }
+
+    impl Fill for OffsetDateTimeRfc3339 {
+        fn fill(&self, slot: Slot) -> Result<(), Error> {
+            let formatted = self
+                .0
+                .format(&Rfc3339)
+                .map_err(|_| Error::msg("failed to format timestamp"))?;
+
+            slot.fill(|visitor| visitor.str(&formatted))
+        }
+    }
+
+    impl<'v> From<&'v OffsetDateTime> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v OffsetDateTime) -> Self {
+            ValueBag::from_offset_date_time(v)
+        }
+    }
+
+    impl<'v> ValueBag<'v> {
+        /// Get a value from a system timestamp.
+        ///
+        /// The timestamp will be captured as an RFC3339 string in UTC.
+        pub const fn from_system_time(value: &'v SystemTime) -> Self {
+            ValueBag {
+                inner: Internal::Fill(SystemTimeRfc3339::new_ref(value)),
+            }
+        }
+    }
+
+    #[repr(transparent)]
+    struct SystemTimeRfc3339(SystemTime);
+
+    impl SystemTimeRfc3339 {
+        const fn new_ref(v: &SystemTime) -> &SystemTimeRfc3339 {
+            // SAFETY: `SystemTimeRfc3339` and `SystemTime` have the same ABI
+            unsafe { &*(v as *const SystemTime as *const SystemTimeRfc3339) }
+        }
+
+        fn to_offset_date_time(&self) -> Option<OffsetDateTime> {
+            let nanos = match self.0.duration_since(UNIX_EPOCH) {
+                Ok(since) => i128::try_from(since.as_nanos()).ok()?,
+                Err(before) => -i128::try_from(before.duration().as_nanos()).ok()?,
+            };
+
+            OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+        }
+    }
+
+    impl Fill for SystemTimeRfc3339 {
+        fn fill(&self, slot: Slot) -> Result<(), Error> {
+            let formatted = self
+                .to_offset_date_time()
+                .and_then(|ts| ts.format(&Rfc3339).ok())
+                .ok_or_else(|| Error::msg("failed to format timestamp"))?;
+
+            slot.fill(|visitor| visitor.str(&formatted))
+        }
+    }
+
+    impl<'v> From<&'v SystemTime> for ValueBag<'v> {
+        #[inline]
+        fn from(v: &'v SystemTime) -> Self {
+            ValueBag::from_system_time(v)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::*;
+
+        use super::*;
+
+        use crate::std::string::ToString;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn offset_date_time_capture() {
+            let ts = OffsetDateTime::from_unix_timestamp(1_000_000_000).unwrap();
+
+            assert_eq!(
+                "2001-09-09T01:46:40Z",
+                ValueBag::from_offset_date_time(&ts).to_str().unwrap()
+            );
+            assert_eq!("2001-09-09T01:46:40Z", ValueBag::from(&ts).to_string());
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn offset_date_time_cast() {
+            let ts = OffsetDateTime::from_unix_timestamp(1_000_000_000).unwrap();
+
+            assert_eq!(Some(ts), ValueBag::from(&ts).to_offset_date_time());
+            assert_eq!(Some(ts), ValueBag::capture_debug(&ts).to_offset_date_time());
+            assert_eq!(
+                Some(ts),
+                ValueBag::from("2001-09-09T01:46:40Z").to_offset_date_time()
+            );
+
+            assert_eq!(None, ValueBag::from("a string").to_offset_date_time());
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn system_time_capture() {
+            use crate::std::time::Duration;
+
+            let ts = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+
+            assert_eq!(
+                "2001-09-09T01:46:40Z",
+                ValueBag::from_system_time(&ts).to_str().unwrap()
+            );
+            assert_eq!("2001-09-09T01:46:40Z", ValueBag::from(&ts).to_string());
+
+            let ts = UNIX_EPOCH - Duration::from_secs(1);
+
+            assert_eq!("1969-12-31T23:59:59Z", ValueBag::from(&ts).to_string());
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_support {
+    use ::chrono::{DateTime, FixedOffset, TimeZone};
+
+    use crate::{
+        fill::{Fill, Slot},
+        internal::Internal,
+        std::fmt::Display,
+        Error, ValueBag,
+    };
+
+    impl<'v> ValueBag<'v> {
+        /// Get a value from a `chrono` timestamp.
+        ///
+        /// The timestamp will be captured as an RFC3339 string.
+        pub const fn from_chrono_date_time<Tz>(value: &'v DateTime<Tz>) -> Self
+        where
+            Tz: TimeZone,
+            Tz::Offset: Display,
+        {
+            ValueBag {
+                inner: Internal::Fill(DateTimeRfc3339::new_ref(value)),
+            }
+        }
+
+        /// Try get a `chrono` timestamp from this value.
+        ///
+        /// This method will return `Some` if the value was captured from a `DateTime<FixedOffset>`,
+        /// or if it's an RFC3339 string.
+        pub fn to_chrono_date_time(&self) -> Option<DateTime<FixedOffset>> {
+            if let Some(value) = self.downcast_ref::<DateTime<FixedOffset>>() {
+                return Some(*value);
+            }
+
+            DateTime::parse_from_rfc3339(&self.to_str()?).ok()
+        }
+    }
+
+    #[repr(transparent)]
+    struct DateTimeRfc3339<Tz: TimeZone>(DateTime<Tz>);
+
+    impl<Tz: TimeZone> DateTimeRfc3339<Tz> {
+        const fn new_ref(v: &DateTime<Tz>) -> &DateTimeRfc3339<Tz> {
+            // SAFETY: `DateTimeRfc3339<Tz>` and `DateTime<Tz>` have the same ABI
+            unsafe { &*(v as *const DateTime<Tz> as *const DateTimeRfc3339<Tz>) }
+        }
+    }
+
+    impl<Tz: TimeZone> Fill for DateTimeRfc3339<Tz>
+    where
+        Tz::Offset: Display,
+    {
+        fn fill(&self, slot: Slot) -> Result<(), Error> {
+            let formatted = self.0.to_rfc3339();
+
+            slot.fill(|visitor| visitor.str(&formatted))
+        }
+    }
+
+    impl<'v, Tz> From<&'v DateTime<Tz>> for ValueBag<'v>
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        #[inline]
+        fn from(v: &'v DateTime<Tz>) -> Self {
+            ValueBag::from_chrono_date_time(v)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::*;
+
+        use super::*;
+
+        use crate::std::string::ToString;
+
+        use ::chrono::Utc;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn chrono_date_time_capture() {
+            let ts = Utc.timestamp_opt(1_000_000_000, 0).unwrap();
+
+            assert_eq!(
+                "2001-09-09T01:46:40+00:00",
+                ValueBag::from_chrono_date_time(&ts).to_str().unwrap()
+            );
+            assert_eq!("2001-09-09T01:46:40+00:00", ValueBag::from(&ts).to_string());
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn chrono_date_time_cast() {
+            let ts = Utc.timestamp_opt(1_000_000_000, 0).unwrap();
+
+            assert_eq!(
+                Some(ts.fixed_offset()),
+                ValueBag::from(&ts).to_chrono_date_time()
+            );
+            assert_eq!(
+                Some(ts.fixed_offset()),
+                ValueBag::capture_debug(&ts.fixed_offset()).to_chrono_date_time()
+            );
+
+            assert_eq!(None, ValueBag::from("a string").to_chrono_date_time());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Integration between `Value` and decimals from the `rust_decimal` crate.
+//!
+//! Decimals are captured as strings, so they keep their exact precision
+//! regardless of the framework used to serialize them.
+
+use rust_decimal::Decimal;
+
+use crate::{
+    fill::{Fill, Slot},
+    std::string::ToString,
+    Error, ValueBag,
+};
+
+use super::Internal;
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a `rust_decimal` decimal.
+    ///
+    /// The decimal will be captured as a string.
+    pub const fn from_decimal(value: &'v Decimal) -> Self {
+        ValueBag {
+            inner: Internal::Fill(DecimalStr::new_ref(value)),
+        }
+    }
+
+    /// Try get a `rust_decimal` decimal from this value.
+    ///
+    /// This method will return `Some` if the value was captured from a `Decimal`,
+    /// or if it's a string containing a decimal number.
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        if let Some(value) = self.downcast_ref::<Decimal>() {
+            return Some(*value);
+        }
+
+        self.to_str()?.parse().ok()
+    }
+}
+
+#[repr(transparent)]
+struct DecimalStr(Decimal);
+
+impl DecimalStr {
+    const fn new_ref(v: &Decimal) -> &DecimalStr {
+        // SAFETY: `DecimalStr` and `Decimal` have the same ABI
+        unsafe { &*(v as *const Decimal as *const DecimalStr) }
+    }
+}
+
+impl Fill for DecimalStr {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        let formatted = self.0.to_string();
+
+        slot.fill(|visitor| visitor.str(&formatted))
+    }
+}
+
+impl<'v> From<&'v Decimal> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v Decimal) -> Self {
+        ValueBag::from_decimal(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn decimal_capture() {
+        let decimal = Decimal::new(1_234_500, 4);
+
+        assert_eq!(
+            "123.4500",
+            ValueBag::from_decimal(&decimal).to_str().unwrap()
+        );
+        assert_eq!("123.4500", ValueBag::from(&decimal).to_string());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn decimal_cast() {
+        let decimal = Decimal::new(1_234_500, 4);
+
+        let roundtrip = ValueBag::from(&decimal).to_decimal().unwrap();
+        assert_eq!(decimal, roundtrip);
+        assert_eq!(decimal.scale(), roundtrip.scale());
+
+        assert_eq!(
+            Some(decimal),
+            ValueBag::capture_display(&decimal).to_decimal()
+        );
+        assert_eq!(Some(decimal), ValueBag::from("123.4500").to_decimal());
+
+        assert_eq!(None, ValueBag::from("a string").to_decimal());
+    }
+}
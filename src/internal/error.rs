@@ -25,6 +25,18 @@ impl<'v> ValueBag<'v> {
         }
     }
 
+    /// Get a value from the result of building a value.
+    ///
+    /// If the result is `Ok` then the bag it contains is returned.
+    /// If the result is `Err` then the error is captured as the value.
+    #[inline]
+    pub fn from_result_bag(value: &'v Result<ValueBag<'v>, crate::Error>) -> Self {
+        match value {
+            Ok(value) => value.by_ref(),
+            Err(err) => ValueBag::from_dyn_error(err),
+        }
+    }
+
     /// Try get an error from this value.
     #[inline]
     pub fn to_borrowed_error(&self) -> Option<&'v (dyn Error + 'static)> {
@@ -113,6 +125,18 @@ impl<'v> From<&'v (dyn error::Error + 'static)> for ValueBag<'v> {
     }
 }
 
+/// Capture an erased error that's `Send + Sync`.
+///
+/// `ValueBag` borrows its contents, so it isn't `Send` or `Sync` itself.
+/// To send a captured error across threads, convert it into an `OwnedValueBag`
+/// using `ValueBag::to_shared` from the `owned` feature, which is `Send + Sync`.
+impl<'v> From<&'v (dyn error::Error + Send + Sync + 'static)> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v (dyn error::Error + Send + Sync + 'static)) -> Self {
+        ValueBag::from_dyn_error(v)
+    }
+}
+
 impl<'v> From<Option<&'v (dyn error::Error + 'static)>> for ValueBag<'v> {
     #[inline]
     fn from(v: Option<&'v (dyn error::Error + 'static)>) -> Self {
@@ -120,6 +144,13 @@ impl<'v> From<Option<&'v (dyn error::Error + 'static)>> for ValueBag<'v> {
     }
 }
 
+impl<'v> From<&'v Result<ValueBag<'v>, crate::Error>> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v Result<ValueBag<'v>, crate::Error>) -> Self {
+        ValueBag::from_result_bag(v)
+    }
+}
+
 impl<'v> TryFrom<ValueBag<'v>> for &'v (dyn error::Error + 'static) {
     type Error = crate::Error;
 
@@ -174,6 +205,53 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn error_sync_capture() {
+        let err: crate::std::boxed::Box<dyn error::Error + Send + Sync> =
+            io::Error::from(io::ErrorKind::Other).into();
+
+        let value = ValueBag::from(&*err);
+
+        assert_eq!(
+            err.to_string(),
+            value
+                .to_borrowed_error()
+                .expect("invalid value")
+                .to_string()
+        );
+
+        #[cfg(feature = "owned")]
+        {
+            fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+            let value = value.to_shared();
+            assert_send_sync(&value);
+
+            assert_eq!(err.to_string(), value.to_string());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn error_result_bag() {
+        let ok: Result<ValueBag, crate::Error> = Ok(ValueBag::from(42u64));
+
+        assert_eq!(Some(42), ValueBag::from_result_bag(&ok).to_u64());
+        assert!(ValueBag::from(&ok).to_borrowed_error().is_none());
+
+        let err: Result<ValueBag, crate::Error> = Err(crate::Error::msg("failed to build"));
+
+        assert_eq!(
+            "failed to build",
+            ValueBag::from_result_bag(&err)
+                .to_borrowed_error()
+                .expect("invalid value")
+                .to_string()
+        );
+        assert!(ValueBag::from(&err).to_u64().is_none());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn error_downcast() {
@@ -4,7 +4,7 @@
 //! and for any `Debug` or `Display` to be captured as a `Value`.
 
 use crate::{
-    fill::Slot,
+    fill::{Fill, Slot},
     std::{any::Any, fmt},
     Error, ValueBag,
 };
@@ -16,6 +16,10 @@ impl<'v> ValueBag<'v> {
     ///
     /// This method will attempt to capture the given value as a well-known primitive
     /// before resorting to using its `Debug` implementation.
+    ///
+    /// The type `T` must be `'static`, so a short-lived `&'a str` can't be captured
+    /// using this method. Prefer `ValueBag::from` for strings, which captures any
+    /// `&'v str` as a string directly.
     pub fn capture_debug<T>(value: &'v T) -> Self
     where
         T: Debug + 'static,
@@ -25,6 +29,36 @@ impl<'v> ValueBag<'v> {
         })
     }
 
+    /// Get a value from a debuggable type, retaining the name of its type.
+    ///
+    /// Unlike [`ValueBag::capture_debug`], this method won't attempt to capture
+    /// the given value as a well-known primitive. The name of `T` can be read back
+    /// using [`ValueBag::type_name`]. It's only intended for diagnostics.
+    pub const fn capture_debug_named<T>(value: &'v T) -> Self
+    where
+        T: Debug + 'static,
+    {
+        ValueBag {
+            inner: Internal::Debug(value),
+        }
+    }
+
+    /// Get the name of the type this value was captured from.
+    ///
+    /// This method will return `Some` for values captured using their `Debug`
+    /// implementation, like [`ValueBag::capture_debug_named`]. The name is
+    /// informational only, and its exact format isn't guaranteed.
+    pub fn type_name(&self) -> Option<&'static str> {
+        match self.inner {
+            Internal::Debug(value) => Some(value.type_name()),
+            #[cfg(feature = "owned")]
+            Internal::SharedDebug(ref value) => Some(value.type_name()),
+            #[cfg(feature = "owned")]
+            Internal::SharedRefDebug(value) => Some(value.type_name()),
+            _ => None,
+        }
+    }
+
     /// Get a value from a displayable type.
     ///
     /// This method will attempt to capture the given value as a well-known primitive
@@ -58,6 +92,48 @@ impl<'v> ValueBag<'v> {
         }
     }
 
+    /// Get a value from pre-formatted arguments, like those produced by `format_args!`.
+    ///
+    /// The arguments are rendered whenever the value is displayed. When the value is buffered
+    /// using [`ValueBag::to_owned`] the arguments are rendered once with default formatting flags,
+    /// because the flags the owned value will later be formatted with aren't known yet.
+    /// Use [`ValueBag::to_owned_with_flags`] to render the arguments with a specific width
+    /// or precision instead.
+    pub const fn from_args(value: &'v fmt::Arguments<'v>) -> Self {
+        ValueBag::from_display(value)
+    }
+
+    /// Get a value from a set of format arguments, paired with a value that can be
+    /// recovered from it.
+    ///
+    /// The arguments are used when the value is formatted using `Debug` or `Display`.
+    /// Other conversions, like [`ValueBag::to_u64`], and other serialization frameworks
+    /// use the paired value instead.
+    pub const fn from_args_with_value(
+        args: &'v fmt::Arguments<'v>,
+        value: &'v ValueBag<'v>,
+    ) -> Self {
+        ValueBag {
+            inner: Internal::ArgsWithValue(args, &value.inner),
+        }
+    }
+
+    /// Get a value from a `bool` that's formatted using the given labels.
+    ///
+    /// The labels are given as `(on, off)`. The `on` label is used when the value is `true`,
+    /// and the `off` label is used when it's `false`. The labels are only used when the value
+    /// is formatted using `Debug` or `Display`. Other conversions, like [`ValueBag::to_bool`],
+    /// use the `bool` itself.
+    pub const fn from_bool_styled(value: bool, labels: &'v (&'v str, &'v str)) -> Self {
+        ValueBag {
+            inner: if value {
+                Internal::Fill(StyledBool::<true>::new_ref(labels))
+            } else {
+                Internal::Fill(StyledBool::<false>::new_ref(labels))
+            },
+        }
+    }
+
     /// Get a value from a debuggable type without capturing support.
     #[inline]
     pub const fn from_dyn_debug(value: &'v dyn Debug) -> Self {
@@ -73,6 +149,259 @@ impl<'v> ValueBag<'v> {
             inner: Internal::AnonDisplay(value),
         }
     }
+
+    /// Get a value from an optional debuggable type without capturing support.
+    ///
+    /// If the value is `None` then the empty value will be returned.
+    #[inline]
+    pub const fn from_option_debug(value: Option<&'v dyn Debug>) -> Self {
+        match value {
+            Some(value) => ValueBag::from_dyn_debug(value),
+            None => ValueBag::empty(),
+        }
+    }
+
+    /// Get a value from a function that renders text into a writer.
+    ///
+    /// The function will be called with the underlying formatter whenever
+    /// the value is displayed.
+    pub const fn from_render<F>(value: &'v F) -> Self
+    where
+        F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+    {
+        ValueBag {
+            inner: Internal::AnonDisplay(Render::new_ref(value)),
+        }
+    }
+
+    /// Get a value from the successful outcome of an operation.
+    ///
+    /// The value is tagged with `Ok` and formatted using its `Debug` implementation,
+    /// so it will display as `Ok(value)`. This doesn't require the `error` feature.
+    pub const fn from_ok<T>(value: &'v T) -> Self
+    where
+        T: Debug,
+    {
+        ValueBag {
+            inner: Internal::AnonDisplay(TaggedOk::new_ref(value)),
+        }
+    }
+
+    /// Get a value from the failed outcome of an operation.
+    ///
+    /// The error is tagged with `Err` and formatted using its `Debug` implementation,
+    /// so it will display as `Err(error)`. This doesn't require the `error` feature.
+    pub const fn from_err<E>(err: &'v E) -> Self
+    where
+        E: Debug,
+    {
+        ValueBag {
+            inner: Internal::AnonDisplay(TaggedErr::new_ref(err)),
+        }
+    }
+
+    /// Get a value from a sequence of Latin-1 encoded bytes.
+    ///
+    /// The bytes will be decoded as Latin-1 when the value is formatted, so each
+    /// byte is rendered as the character with the same code point. This can be
+    /// useful for byte strings that are mostly text, but may not be valid UTF-8.
+    pub const fn from_latin1<B>(value: &'v B) -> Self
+    where
+        B: AsRef<[u8]>,
+    {
+        ValueBag {
+            inner: Internal::AnonDisplay(Latin1::new_ref(value)),
+        }
+    }
+
+    /// Get a value from a sequence of bytes that's formatted as hex.
+    ///
+    /// The bytes will be rendered as lowercase hex when the value is formatted
+    /// using `Debug` or `Display`. Other conversions, like [`ValueBag::to_bytes`],
+    /// use the bytes themselves.
+    pub const fn from_bytes_hex<B>(value: &'v B) -> Self
+    where
+        B: AsRef<[u8]>,
+    {
+        ValueBag {
+            inner: Internal::Fill(HexBytes::new_ref(value)),
+        }
+    }
+
+    /// Get the number of bytes in the `Display` representation of this value.
+    ///
+    /// The value is formatted into a writer that only counts the bytes written,
+    /// so this method doesn't allocate. It can be used to pre-size a buffer.
+    pub fn display_len(&self) -> usize {
+        use fmt::Write as _;
+
+        struct Count(usize);
+
+        impl fmt::Write for Count {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut count = Count(0);
+        let _ = write!(count, "{}", self);
+
+        count.0
+    }
+}
+
+#[repr(transparent)]
+struct Render<F>(F);
+
+impl<F> Render<F> {
+    const fn new_ref(v: &F) -> &Render<F> {
+        // SAFETY: `Render<F>` and `F` have the same ABI
+        unsafe { &*(v as *const F as *const Render<F>) }
+    }
+}
+
+#[repr(transparent)]
+struct TaggedOk<T>(T);
+
+impl<T> TaggedOk<T> {
+    const fn new_ref(v: &T) -> &TaggedOk<T> {
+        // SAFETY: `TaggedOk<T>` and `T` have the same ABI
+        unsafe { &*(v as *const T as *const TaggedOk<T>) }
+    }
+}
+
+impl<T: Debug> Display for TaggedOk<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Ok").field(&self.0).finish()
+    }
+}
+
+#[repr(transparent)]
+struct TaggedErr<E>(E);
+
+impl<E> TaggedErr<E> {
+    const fn new_ref(v: &E) -> &TaggedErr<E> {
+        // SAFETY: `TaggedErr<E>` and `E` have the same ABI
+        unsafe { &*(v as *const E as *const TaggedErr<E>) }
+    }
+}
+
+impl<E: Debug> Display for TaggedErr<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Err").field(&self.0).finish()
+    }
+}
+
+#[repr(transparent)]
+struct StyledBool<'a, const VALUE: bool>((&'a str, &'a str));
+
+impl<'a, const VALUE: bool> StyledBool<'a, VALUE> {
+    const fn new_ref<'b>(v: &'b (&'a str, &'a str)) -> &'b StyledBool<'a, VALUE> {
+        // SAFETY: `StyledBool<VALUE>` and `(&str, &str)` have the same ABI
+        unsafe { &*(v as *const (&'a str, &'a str) as *const StyledBool<'a, VALUE>) }
+    }
+}
+
+impl<'a, const VALUE: bool> Fill for StyledBool<'a, VALUE> {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        let (on, off) = self.0;
+        let label = if VALUE { on } else { off };
+
+        slot.fill(|visitor| {
+            visitor.args_with_value(&format_args!("{}", label), &Internal::Bool(VALUE))
+        })
+    }
+}
+
+pub(crate) struct OrderingName(crate::std::cmp::Ordering);
+
+impl OrderingName {
+    pub(crate) const fn new_ref(v: crate::std::cmp::Ordering) -> &'static OrderingName {
+        use crate::std::cmp::Ordering;
+
+        match v {
+            Ordering::Less => &OrderingName(Ordering::Less),
+            Ordering::Equal => &OrderingName(Ordering::Equal),
+            Ordering::Greater => &OrderingName(Ordering::Greater),
+        }
+    }
+}
+
+impl Fill for OrderingName {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        slot.fill(|visitor| {
+            visitor.args_with_value(
+                &format_args!("{:?}", self.0),
+                &Internal::Signed(self.0 as i64),
+            )
+        })
+    }
+}
+
+#[repr(transparent)]
+struct Latin1<B>(B);
+
+impl<B> Latin1<B> {
+    const fn new_ref(v: &B) -> &Latin1<B> {
+        // SAFETY: `Latin1<B>` and `B` have the same ABI
+        unsafe { &*(v as *const B as *const Latin1<B>) }
+    }
+}
+
+impl<B: AsRef<[u8]>> Display for Latin1<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use fmt::Write as _;
+
+        for b in self.0.as_ref() {
+            f.write_char(char::from(*b))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(transparent)]
+struct HexBytes<B>(B);
+
+impl<B> HexBytes<B> {
+    const fn new_ref(v: &B) -> &HexBytes<B> {
+        // SAFETY: `HexBytes<B>` and `B` have the same ABI
+        unsafe { &*(v as *const B as *const HexBytes<B>) }
+    }
+}
+
+impl<B: AsRef<[u8]>> Fill for HexBytes<B> {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        let bytes = self.0.as_ref();
+
+        slot.fill(|visitor| {
+            visitor.args_with_fill(&format_args!("{}", Hex(bytes)), &|slot: Slot| {
+                slot.fill(|visitor| visitor.bytes(bytes))
+            })
+        })
+    }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl<'a> Display for Hex<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F> Display for Render<F>
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0)(f)
+    }
 }
 
 pub(crate) trait DowncastDisplay {
@@ -99,6 +428,7 @@ impl<'a> fmt::Display for dyn DowncastDisplay + Send + Sync + 'a {
 pub(crate) trait DowncastDebug {
     fn as_any(&self) -> &dyn Any;
     fn as_super(&self) -> &dyn fmt::Debug;
+    fn type_name(&self) -> &'static str;
 }
 
 impl<T: fmt::Debug + 'static> DowncastDebug for T {
@@ -109,6 +439,10 @@ impl<T: fmt::Debug + 'static> DowncastDebug for T {
     fn as_super(&self) -> &dyn fmt::Debug {
         self
     }
+
+    fn type_name(&self) -> &'static str {
+        crate::std::any::type_name::<T>()
+    }
 }
 
 impl<'a> fmt::Debug for dyn DowncastDebug + Send + Sync + 'a {
@@ -210,6 +544,12 @@ impl<'v> Debug for ValueBag<'v> {
                 Ok(())
             }
 
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                Debug::fmt(&v, self.0)?;
+
+                Ok(())
+            }
+
             fn none(&mut self) -> Result<(), Error> {
                 self.debug(&format_args!("None"))
             }
@@ -243,6 +583,27 @@ impl<'v> Debug for ValueBag<'v> {
                 Ok(())
             }
 
+            #[cfg(feature = "map")]
+            fn map(&mut self, map: &dyn crate::internal::map::Map) -> Result<(), Error> {
+                let mut visitor = map::FmtMap(self.0.debug_map());
+                map.visit(&mut visitor);
+                visitor.0.finish()?;
+
+                Ok(())
+            }
+
+            fn args_with_value(
+                &mut self,
+                args: &fmt::Arguments,
+                _: &Internal<'v>,
+            ) -> Result<(), Error> {
+                self.display(args)
+            }
+
+            fn args_with_fill(&mut self, args: &fmt::Arguments, _: &dyn Fill) -> Result<(), Error> {
+                self.display(args)
+            }
+
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
                 write!(self.0, "<{msg}>")?;
 
@@ -326,6 +687,12 @@ impl<'v> Display for ValueBag<'v> {
                 Ok(())
             }
 
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                Debug::fmt(&v, self.0)?;
+
+                Ok(())
+            }
+
             fn none(&mut self) -> Result<(), Error> {
                 self.debug(&format_args!("None"))
             }
@@ -352,6 +719,10 @@ impl<'v> Display for ValueBag<'v> {
 
             #[cfg(feature = "seq")]
             fn seq(&mut self, seq: &dyn crate::internal::seq::Seq) -> Result<(), Error> {
+                if let Some(r) = seq.fmt_display(self.0) {
+                    return Ok(r?);
+                }
+
                 let mut visitor = seq::FmtSeq(self.0.debug_list());
                 seq.visit(&mut visitor);
                 visitor.0.finish()?;
@@ -359,6 +730,27 @@ impl<'v> Display for ValueBag<'v> {
                 Ok(())
             }
 
+            #[cfg(feature = "map")]
+            fn map(&mut self, map: &dyn crate::internal::map::Map) -> Result<(), Error> {
+                let mut visitor = map::FmtMap(self.0.debug_map());
+                map.visit(&mut visitor);
+                visitor.0.finish()?;
+
+                Ok(())
+            }
+
+            fn args_with_value(
+                &mut self,
+                args: &fmt::Arguments,
+                _: &Internal<'v>,
+            ) -> Result<(), Error> {
+                self.display(args)
+            }
+
+            fn args_with_fill(&mut self, args: &fmt::Arguments, _: &dyn Fill) -> Result<(), Error> {
+                self.display(args)
+            }
+
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
                 write!(self.0, "<{msg}>")?;
 
@@ -388,6 +780,21 @@ mod seq {
     }
 }
 
+#[cfg(feature = "map")]
+mod map {
+    use super::*;
+    use core::ops::ControlFlow;
+
+    pub(super) struct FmtMap<'a, 'b>(pub(super) fmt::DebugMap<'b, 'a>);
+
+    impl<'a, 'b, 'c> crate::internal::map::Visitor<'c> for FmtMap<'a, 'b> {
+        fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()> {
+            self.0.entry(&k, &v);
+            ControlFlow::Continue(())
+        }
+    }
+}
+
 #[cfg(feature = "owned")]
 pub(crate) mod owned {
     use crate::std::{boxed::Box, fmt, string::ToString};
@@ -428,6 +835,13 @@ pub(crate) mod owned {
     }
 }
 
+impl<'v> From<&'v fmt::Arguments<'v>> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v fmt::Arguments<'v>) -> Self {
+        ValueBag::from_args(v)
+    }
+}
+
 impl<'v> From<&'v dyn Debug> for ValueBag<'v> {
     #[inline]
     fn from(v: &'v dyn Debug) -> Self {
@@ -438,7 +852,7 @@ impl<'v> From<&'v dyn Debug> for ValueBag<'v> {
 impl<'v> From<Option<&'v dyn Debug>> for ValueBag<'v> {
     #[inline]
     fn from(v: Option<&'v dyn Debug>) -> Self {
-        ValueBag::from_option(v)
+        ValueBag::from_option_debug(v)
     }
 }
 
@@ -505,6 +919,45 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_capture_short_lived_str() {
+        let owned = crate::std::string::String::from("a string");
+        let short_lived: &str = &owned;
+
+        // `capture_debug` needs `T: 'static`, so only works for `&'static str`
+        assert_eq!(
+            ValueBag::capture_debug(&"a string").to_borrowed_str(),
+            Some("a string")
+        );
+
+        // `From` works for any `&'v str`
+        assert_eq!(
+            ValueBag::from(short_lived).to_borrowed_str(),
+            Some("a string")
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_display_short_lived() {
+        struct Greeting<'a>(&'a str);
+
+        impl<'a> Display for Greeting<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "hello, {}", self.0)
+            }
+        }
+
+        let owned = crate::std::string::String::from("world");
+        let greeting = Greeting(&owned);
+
+        // `from_display` works for any `T: Display`, without needing `T: 'static`
+        let value = ValueBag::from_display(&greeting);
+
+        assert_eq!("hello, world", value.to_string());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn fmt_fill() {
@@ -527,6 +980,133 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_option_debug() {
+        let value = ValueBag::from_option_debug(Some(&42));
+        assert_eq!("42", format!("{:?}", value));
+        assert!(!value.is_empty());
+
+        let value = ValueBag::from_option_debug(None);
+        assert!(value.is_empty());
+
+        assert!(ValueBag::from(None::<&dyn Debug>).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_args_with_value() {
+        let n = 42u64;
+        let value = ValueBag::from(n);
+
+        let check = |bag: ValueBag| {
+            assert_eq!(Some(42), bag.to_u64());
+            assert_eq!("n = 42", format!("{}", bag));
+            assert_eq!("n = 42", format!("{:?}", bag));
+        };
+
+        check(ValueBag::from_args_with_value(
+            &format_args!("n = {n}"),
+            &value,
+        ));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_bool_styled() {
+        let on = ValueBag::from_bool_styled(true, &("enabled", "disabled"));
+        let off = ValueBag::from_bool_styled(false, &("enabled", "disabled"));
+
+        assert_eq!(Some(true), on.to_bool());
+        assert_eq!(Some(false), off.to_bool());
+
+        assert_eq!("enabled", format!("{}", on));
+        assert_eq!("disabled", format!("{}", off));
+        assert_eq!("disabled", format!("{:?}", off));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_bytes_hex() {
+        let value = ValueBag::from_bytes_hex(&[0xde, 0xad]);
+
+        assert_eq!("dead", format!("{}", value));
+        assert_eq!("dead", format!("{:?}", value));
+        #[cfg(feature = "alloc")]
+        assert_eq!(Some(&[0xde, 0xad][..]), value.to_bytes().as_deref());
+
+        assert_eq!("", format!("{}", ValueBag::from_bytes_hex(b"")));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_display_len() {
+        for value in [
+            ValueBag::from(42u64),
+            ValueBag::from(-1.5f64),
+            ValueBag::from("a string"),
+            ValueBag::from("🦀"),
+            ValueBag::from_display(&format_args!("{} and {}", 1, "two")),
+            ValueBag::empty(),
+        ] {
+            assert_eq!(format!("{}", value).len(), value.display_len());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_render() {
+        use crate::std::{fmt::Write, string::String};
+
+        let n = 42;
+        let render = |w: &mut dyn fmt::Write| write!(w, "a value {n}");
+
+        let mut buf = String::new();
+        write!(buf, "{}", ValueBag::from_render(&render)).unwrap();
+
+        assert_eq!("a value 42", buf);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_ok_err() {
+        assert_eq!("Ok(42)", ValueBag::from_ok(&42).to_string());
+        assert_eq!("Err(\"failed\")", ValueBag::from_err(&"failed").to_string());
+        assert_eq!(
+            "Err(\"failed\")",
+            format!("{:?}", ValueBag::from_err(&"failed"))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_type_name() {
+        #[derive(Debug)]
+        struct Point;
+
+        let value = ValueBag::capture_debug_named(&Point);
+
+        assert!(value.type_name().unwrap().ends_with("Point"));
+        assert_eq!("Point", format!("{:?}", value));
+
+        assert_eq!(
+            Some(crate::std::any::type_name::<u64>()),
+            ValueBag::capture_debug_named(&1u64).type_name()
+        );
+        assert_eq!(None, ValueBag::from(1u64).type_name());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_latin1() {
+        assert_eq!("caf\u{e9}", ValueBag::from_latin1(b"caf\xe9").to_string());
+        assert_eq!(
+            "\u{a3}5 \u{ff}",
+            ValueBag::from_latin1(&[0xa3, b'5', b' ', 0xff]).to_string()
+        );
+        assert_eq!("", ValueBag::from_latin1(&[]).to_string());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn fmt_cast() {
@@ -624,4 +1204,27 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "map")]
+    mod map_support {
+        use super::*;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn fmt_debug_map() {
+            assert_eq!(
+                "{\"a\": 01, \"b\": 02}",
+                format!("{:>02?}", ValueBag::from_entries(&[("a", 1), ("b", 2)]))
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn fmt_display_map() {
+            assert_eq!(
+                "{\"a\": 1, \"b\": 2}",
+                format!("{}", ValueBag::from_entries(&[("a", 1), ("b", 2)]))
+            );
+        }
+    }
 }
@@ -0,0 +1,99 @@
+//! Integration between `Value` and `half`.
+//!
+//! Half-precision floats are widened to `f64` when they're captured.
+//! Every `f16` is exactly representable as an `f64`, so this is lossless.
+
+use half::f16;
+
+use crate::{Error, ValueBag};
+
+use super::Internal;
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a half-precision float.
+    ///
+    /// The value will be losslessly widened to an `f64`.
+    pub fn from_f16(value: f16) -> Self {
+        ValueBag {
+            inner: Internal::Float(value.to_f64()),
+        }
+    }
+
+    /// Try get a half-precision float from this value.
+    ///
+    /// This method will return `Some` if the value is a float that can be
+    /// narrowed to an `f16` without losing precision.
+    pub fn to_f16(&self) -> Option<f16> {
+        let value = self.to_f64()?;
+        let narrowed = f16::from_f64(value);
+
+        if narrowed.to_f64() == value || (value.is_nan() && narrowed.is_nan()) {
+            Some(narrowed)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'v> From<f16> for ValueBag<'v> {
+    #[inline]
+    fn from(v: f16) -> Self {
+        ValueBag::from_f16(v)
+    }
+}
+
+impl<'a, 'v> From<&'a f16> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'a f16) -> Self {
+        ValueBag::from_f16(*v)
+    }
+}
+
+impl<'v> From<Option<f16>> for ValueBag<'v> {
+    #[inline]
+    fn from(v: Option<f16>) -> Self {
+        ValueBag::from_option(v)
+    }
+}
+
+impl<'v> TryFrom<ValueBag<'v>> for f16 {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(v: ValueBag<'v>) -> Result<Self, Error> {
+        v.to_f16().ok_or_else(|| Error::msg("conversion failed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::test::TestToken;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn f16_capture() {
+        assert_eq!(
+            TestToken::F64(1.5),
+            ValueBag::from_f16(f16::from_f32(1.5)).to_test_token()
+        );
+        assert_eq!(Some(65504.0), ValueBag::from(f16::MAX).to_f64());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn f16_cast() {
+        for v in [f16::ZERO, f16::ONE, f16::MIN_POSITIVE, f16::MAX, f16::MIN] {
+            assert_eq!(Some(v), ValueBag::from(v).to_f16());
+        }
+
+        assert!(ValueBag::from(f16::NAN).to_f16().unwrap().is_nan());
+
+        assert_eq!(Some(f16::from_f32(2.0)), ValueBag::from(2u8).to_f16());
+        assert_eq!(None, ValueBag::from(0.1f64).to_f16());
+        assert_eq!(None, ValueBag::from("1.5").to_f16());
+    }
+}
@@ -0,0 +1,77 @@
+//! Capturing strings through a user-provided interner.
+//!
+//! Interned strings live for `'static`, so values backed by them
+//! can be captured without borrowing the original string.
+
+use crate::ValueBag;
+
+/// A store of strings that can be shared for the life of the program.
+///
+/// Implementations are expected to return the same `&'static str`
+/// for strings with the same contents.
+pub trait Interner {
+    /// Get the interned version of a string.
+    fn intern(&self, value: &str) -> &'static str;
+}
+
+impl ValueBag<'static> {
+    /// Get a value from a string interned using the given interner.
+    ///
+    /// The value will be backed by the interned `&'static str`
+    /// rather than the string that was passed in.
+    pub fn from_interned(interner: &dyn Interner, value: &str) -> Self {
+        ValueBag::from_str(interner.intern(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    use crate::std::{
+        boxed::Box,
+        collections::HashSet,
+        string::{String, ToString},
+        sync::Mutex,
+    };
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_interned() {
+        #[derive(Default)]
+        struct SetInterner(Mutex<HashSet<&'static str>>);
+
+        impl Interner for SetInterner {
+            fn intern(&self, value: &str) -> &'static str {
+                let mut set = self.0.lock().unwrap();
+
+                if let Some(interned) = set.get(value) {
+                    return interned;
+                }
+
+                let interned = Box::leak(String::from(value).into_boxed_str());
+                set.insert(interned);
+
+                interned
+            }
+        }
+
+        let interner = SetInterner::default();
+
+        let a = "a string".to_string();
+        let b = "a string".to_string();
+
+        let a = ValueBag::from_interned(&interner, &a)
+            .to_borrowed_str()
+            .unwrap();
+        let b = ValueBag::from_interned(&interner, &b)
+            .to_borrowed_str()
+            .unwrap();
+
+        assert_eq!("a string", a);
+        assert!(crate::std::ptr::eq(a, b));
+    }
+}
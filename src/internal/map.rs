@@ -0,0 +1,763 @@
+use crate::{
+    impls::Element,
+    internal::{Internal, InternalVisitor},
+    std::{any::Any, fmt, marker::PhantomData, mem, ops::ControlFlow},
+    Error, ValueBag,
+};
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a map of key-value pairs without capturing support.
+    ///
+    /// This is the map analog of [`ValueBag::from_seq_slice`].
+    pub fn from_entries<I, K, V>(value: &'v I) -> Self
+    where
+        I: AsRef<[(K, V)]>,
+        &'v K: Into<ValueBag<'v>> + 'v,
+        &'v V: Into<ValueBag<'v>> + 'v,
+    {
+        ValueBag {
+            inner: Internal::AnonMap(EntrySlice::new_ref(value)),
+        }
+    }
+
+    /// Get a value from a set of named fields.
+    ///
+    /// The fields will be captured as a map keyed by their names.
+    /// This is a convenient shape for structured records, like log events.
+    pub fn from_fields<I>(fields: &'v I) -> Self
+    where
+        I: AsRef<[(&'v str, ValueBag<'v>)]>,
+    {
+        ValueBag::from_entries(fields)
+    }
+
+    /// Get a value from a tuple of labeled pairs.
+    ///
+    /// Each pair in the tuple is captured as an entry in a map, so
+    /// `(("a", 1), ("b", 2))` becomes `{ a: 1, b: 2 }`. Unlike slices of entries,
+    /// each value in the tuple can be a different type.
+    pub fn from_labeled<T>(value: &'v T) -> Self
+    where
+        T: LabeledTuple,
+    {
+        value.to_value_bag()
+    }
+
+    pub(crate) const fn from_dyn_map(value: &'v dyn Map) -> Self {
+        ValueBag {
+            inner: Internal::AnonMap(value),
+        }
+    }
+
+    /// Try get a collection `S` of key-value pairs from this value.
+    ///
+    /// If this value is a map then the collection `S` will be extended
+    /// with the attempted conversion of each of its keys and values.
+    ///
+    /// If this value is not a map then this method will return `None`.
+    pub fn to_map<K, V, S>(&self) -> Option<S>
+    where
+        K: for<'b> TryFrom<ValueBag<'b>>,
+        V: for<'b> TryFrom<ValueBag<'b>>,
+        S: Default + Extend<(Option<K>, Option<V>)>,
+    {
+        self.inner
+            .extend_map::<ExtendPrimitive<S, K, V>>()
+            .map(|map| map.into_inner())
+    }
+}
+
+/*
+This is a bit of an ugly way of working around the gulf between
+lifetimes expressed externally as bounds, and lifetimes implied
+on methods.
+*/
+
+#[repr(transparent)]
+struct EntrySlice<'a, I: ?Sized, K, V>(PhantomData<&'a [(K, V)]>, I);
+
+impl<'a, I: AsRef<[(K, V)]> + ?Sized + 'a, K, V> EntrySlice<'a, I, K, V> {
+    fn new_ref(v: &'a I) -> &'a EntrySlice<'a, I, K, V> {
+        // SAFETY: `EntrySlice<'a, I, K, V>` and `I` have the same ABI
+        unsafe { &*(v as *const I as *const EntrySlice<'a, I, K, V>) }
+    }
+
+    fn as_ref<'b>(&'b self) -> &'a [(K, V)] {
+        // SAFETY: `new_ref` requires there's a borrow of `&'a I`
+        // on the borrow stack, so we can safely borrow it for `'a` here
+        let inner = unsafe { mem::transmute::<&'b I, &'a I>(&self.1) };
+
+        inner.as_ref()
+    }
+}
+
+impl<'a, I, K, V> Map for EntrySlice<'a, I, K, V>
+where
+    I: AsRef<[(K, V)]> + ?Sized + 'a,
+    &'a K: Into<ValueBag<'a>>,
+    &'a V: Into<ValueBag<'a>>,
+{
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        for (k, v) in self.as_ref().iter() {
+            if let ControlFlow::Break(()) = visitor.entry(k.into(), v.into()) {
+                return;
+            }
+        }
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        for (k, v) in self.as_ref().iter() {
+            if let ControlFlow::Break(()) = visitor.borrowed_entry(k.into(), v.into()) {
+                return;
+            }
+        }
+    }
+}
+
+// Slices and arrays of `Element` pairs are maps themselves,
+// so they can be captured without wrapping them, see `impls::Element`
+impl<K: Element, V: Element> Map for [(K, V)] {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        for (k, v) in self {
+            if let ControlFlow::Break(()) = visitor.entry(k.to_value_bag(), v.to_value_bag()) {
+                return;
+            }
+        }
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        for (k, v) in self {
+            if let ControlFlow::Break(()) =
+                visitor.borrowed_entry(k.to_value_bag(), v.to_value_bag())
+            {
+                return;
+            }
+        }
+    }
+}
+
+impl<K: Element, V: Element, const N: usize> Map for [(K, V); N] {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        self.as_slice().visit(visitor)
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        self.as_slice().borrowed_visit(visitor)
+    }
+}
+
+pub(crate) trait Map {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>);
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        self.visit(visitor)
+    }
+}
+
+impl<M: Map + ?Sized> Map for &M {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        (**self).visit(visitor)
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        (**self).borrowed_visit(visitor)
+    }
+}
+
+pub(crate) trait Visitor<'v> {
+    fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()>;
+
+    fn borrowed_entry(&mut self, k: ValueBag<'v>, v: ValueBag<'v>) -> ControlFlow<()> {
+        self.entry(k, v)
+    }
+}
+
+impl<'v, T: Visitor<'v> + ?Sized> Visitor<'v> for &mut T {
+    fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()> {
+        (**self).entry(k, v)
+    }
+
+    fn borrowed_entry(&mut self, k: ValueBag<'v>, v: ValueBag<'v>) -> ControlFlow<()> {
+        (**self).borrowed_entry(k, v)
+    }
+}
+
+pub(crate) trait DowncastMap {
+    // Currently only used when `owned` is also available
+    #[allow(dead_code)]
+    fn as_any(&self) -> &dyn Any;
+    fn as_super(&self) -> &dyn Map;
+}
+
+impl<T: Map + 'static> DowncastMap for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_super(&self) -> &dyn Map {
+        self
+    }
+}
+
+impl<'a> Map for dyn DowncastMap + Send + Sync + 'a {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        self.as_super().visit(visitor)
+    }
+}
+
+pub(crate) struct ExtendPrimitive<S, K, V>(S, PhantomData<(K, V)>);
+
+impl<S: Default, K, V> Default for ExtendPrimitive<S, K, V> {
+    fn default() -> Self {
+        ExtendPrimitive(S::default(), PhantomData)
+    }
+}
+
+impl<S, K, V> ExtendPrimitive<S, K, V> {
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<'a, S, K, V> ExtendEntry<'a> for ExtendPrimitive<S, K, V>
+where
+    S: Extend<(Option<K>, Option<V>)>,
+    K: for<'b> TryFrom<ValueBag<'b>>,
+    V: for<'b> TryFrom<ValueBag<'b>>,
+{
+    fn extend(&mut self, k: Internal, v: Internal) {
+        self.0.extend(Some((
+            ValueBag { inner: k }.try_into().ok(),
+            ValueBag { inner: v }.try_into().ok(),
+        )))
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) trait ExtendEntry<'v> {
+    fn extend(&mut self, k: Internal, v: Internal);
+
+    fn extend_borrowed(&mut self, k: Internal<'v>, v: Internal<'v>) {
+        self.extend(k, v);
+    }
+}
+
+struct ExtendVisitor<S>(S);
+
+impl<'v, S: ExtendEntry<'v>> Visitor<'v> for ExtendVisitor<S> {
+    fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()> {
+        self.0.extend(k.inner, v.inner);
+        ControlFlow::Continue(())
+    }
+
+    fn borrowed_entry(&mut self, k: ValueBag<'v>, v: ValueBag<'v>) -> ControlFlow<()> {
+        self.0.extend_borrowed(k.inner, v.inner);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'v> Internal<'v> {
+    #[inline]
+    pub(crate) fn extend_map<S: Default + ExtendEntry<'v>>(&self) -> Option<S> {
+        self.extend_map_from(S::default())
+    }
+
+    pub(crate) fn extend_map_from<S: ExtendEntry<'v>>(&self, map: S) -> Option<S> {
+        struct MapVisitor<S> {
+            seed: Option<S>,
+            map: Option<S>,
+        }
+
+        impl<'v, S: ExtendEntry<'v>> InternalVisitor<'v> for MapVisitor<S> {
+            #[inline]
+            fn fill(&mut self, v: &dyn crate::fill::Fill) -> Result<(), Error> {
+                v.fill(crate::fill::Slot::new(self))
+            }
+
+            #[inline]
+            fn debug(&mut self, _: &dyn fmt::Debug) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn display(&mut self, _: &dyn fmt::Display) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn u64(&mut self, _: u64) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn i64(&mut self, _: i64) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn u128(&mut self, _: &u128) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn i128(&mut self, _: &i128) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn f64(&mut self, _: f64) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn bool(&mut self, _: bool) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn char(&mut self, _: char) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn str(&mut self, _: &str) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn bytes(&mut self, _: &[u8]) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[inline]
+            fn none(&mut self) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[cfg(feature = "error")]
+            #[inline]
+            fn error(&mut self, _: &dyn crate::internal::error::Error) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[cfg(feature = "sval2")]
+            #[inline]
+            fn sval2(&mut self, _: &dyn crate::internal::sval::v2::Value) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[cfg(feature = "serde1")]
+            #[inline]
+            fn serde1(
+                &mut self,
+                _: &dyn crate::internal::serde::v1::Serialize,
+            ) -> Result<(), Error> {
+                Ok(())
+            }
+
+            #[cfg(feature = "seq")]
+            #[inline]
+            fn seq(&mut self, _: &dyn crate::internal::seq::Seq) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn map(&mut self, map: &dyn Map) -> Result<(), Error> {
+                if let Some(seed) = self.seed.take() {
+                    let mut s = ExtendVisitor(seed);
+                    map.visit(&mut s);
+                    self.map = Some(s.0);
+                }
+
+                Ok(())
+            }
+
+            fn borrowed_map(&mut self, map: &'v dyn Map) -> Result<(), Error> {
+                if let Some(seed) = self.seed.take() {
+                    let mut s = ExtendVisitor(seed);
+                    map.borrowed_visit(&mut s);
+                    self.map = Some(s.0);
+                }
+
+                Ok(())
+            }
+
+            fn poisoned(&mut self, _: &'static str) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+
+        let mut visitor = MapVisitor {
+            seed: Some(map),
+            map: None,
+        };
+        let _ = self.internal_visit(&mut visitor);
+
+        visitor.map
+    }
+}
+
+impl<'v, K, V, const N: usize> From<&'v [(K, V); N]> for ValueBag<'v>
+where
+    K: Element + 'v,
+    V: Element + 'v,
+{
+    fn from(v: &'v [(K, V); N]) -> Self {
+        ValueBag::from_dyn_map(v)
+    }
+}
+
+impl<'v, 'a, K, V> From<&'v &'a [(K, V)]> for ValueBag<'v>
+where
+    K: Element + 'v,
+    V: Element + 'v,
+{
+    fn from(v: &'v &'a [(K, V)]) -> Self {
+        ValueBag::from_dyn_map(v)
+    }
+}
+
+/// A tuple of labeled pairs that can be captured as a map.
+///
+/// This trait is implemented for tuples of up to 8 `(key, value)` pairs.
+/// It's used by [`ValueBag::from_labeled`] and can't be implemented outside of this crate.
+pub trait LabeledTuple: labeled::Sealed {}
+
+mod labeled {
+    use crate::ValueBag;
+
+    pub trait Sealed {
+        fn to_value_bag(&self) -> ValueBag<'_>;
+    }
+}
+
+// Like tuple sequences, labeled tuples implement `Map` directly and are limited to
+// `Element` keys and values, see `impls::Element` for why
+macro_rules! convert_labeled_tuple(
+    ($(($($i:tt $k:ident $v:ident),+),)*) => {
+        $(
+            impl<$($k: Element, $v: Element),+> Map for ($(($k, $v),)+) {
+                fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+                    let _ = (|| {
+                        $(visitor.entry(self.$i.0.to_value_bag(), self.$i.1.to_value_bag())?;)+
+                        ControlFlow::Continue(())
+                    })();
+                }
+
+                fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+                    let _ = (|| {
+                        $(visitor.borrowed_entry(self.$i.0.to_value_bag(), self.$i.1.to_value_bag())?;)+
+                        ControlFlow::Continue(())
+                    })();
+                }
+            }
+
+            impl<$($k: Element, $v: Element),+> labeled::Sealed for ($(($k, $v),)+) {
+                fn to_value_bag(&self) -> ValueBag<'_> {
+                    ValueBag::from_dyn_map(self)
+                }
+            }
+
+            impl<$($k: Element, $v: Element),+> LabeledTuple for ($(($k, $v),)+) {}
+        )*
+    }
+);
+
+convert_labeled_tuple![
+    (0 K0 V0),
+    (0 K0 V0, 1 K1 V1),
+    (0 K0 V0, 1 K1 V1, 2 K2 V2),
+    (0 K0 V0, 1 K1 V1, 2 K2 V2, 3 K3 V3),
+    (0 K0 V0, 1 K1 V1, 2 K2 V2, 3 K3 V3, 4 K4 V4),
+    (0 K0 V0, 1 K1 V1, 2 K2 V2, 3 K3 V3, 4 K4 V4, 5 K5 V5),
+    (0 K0 V0, 1 K1 V1, 2 K2 V2, 3 K3 V3, 4 K4 V4, 5 K5 V5, 6 K6 V6),
+    (0 K0 V0, 1 K1 V1, 2 K2 V2, 3 K3 V3, 4 K4 V4, 5 K5 V5, 6 K6 V6, 7 K7 V7),
+];
+
+#[cfg(feature = "alloc")]
+mod alloc_support {
+    use super::*;
+
+    use crate::std::collections::BTreeMap;
+
+    impl<K: Element, V: Element> Map for BTreeMap<K, V> {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            for (k, v) in self {
+                if let ControlFlow::Break(()) = visitor.entry(k.to_value_bag(), v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+
+        fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+            for (k, v) in self {
+                if let ControlFlow::Break(()) =
+                    visitor.borrowed_entry(k.to_value_bag(), v.to_value_bag())
+                {
+                    return;
+                }
+            }
+        }
+    }
+
+    impl<'v, K, V> From<&'v BTreeMap<K, V>> for ValueBag<'v>
+    where
+        K: Element + 'v,
+        V: Element + 'v,
+    {
+        fn from(v: &'v BTreeMap<K, V>) -> Self {
+            ValueBag::from_dyn_map(v)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+
+    use crate::std::collections::HashMap;
+
+    impl<K: Element, V: Element, H> Map for HashMap<K, V, H> {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            for (k, v) in self {
+                if let ControlFlow::Break(()) = visitor.entry(k.to_value_bag(), v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+
+        fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+            for (k, v) in self {
+                if let ControlFlow::Break(()) =
+                    visitor.borrowed_entry(k.to_value_bag(), v.to_value_bag())
+                {
+                    return;
+                }
+            }
+        }
+    }
+
+    impl<'v, K, V, H> From<&'v HashMap<K, V, H>> for ValueBag<'v>
+    where
+        K: Element + 'v,
+        V: Element + 'v,
+    {
+        fn from(v: &'v HashMap<K, V, H>) -> Self {
+            ValueBag::from_dyn_map(v)
+        }
+    }
+}
+
+#[cfg(feature = "owned")]
+pub(crate) mod owned {
+    use super::*;
+
+    use crate::{
+        owned::OwnedValueBag,
+        std::{boxed::Box, vec::Vec},
+    };
+
+    #[derive(Clone)]
+    pub(crate) struct OwnedMap(Box<[(OwnedValueBag, OwnedValueBag)]>);
+
+    impl Map for OwnedMap {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            for (k, v) in self.0.iter() {
+                if let ControlFlow::Break(()) = visitor.entry(k.by_ref(), v.by_ref()) {
+                    return;
+                }
+            }
+        }
+    }
+
+    pub(crate) fn buffer(v: &dyn Map) -> Result<OwnedMap, Error> {
+        struct BufferVisitor(Vec<(OwnedValueBag, OwnedValueBag)>);
+
+        impl<'v> Visitor<'v> for BufferVisitor {
+            fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()> {
+                self.0.push((k.to_owned(), v.to_owned()));
+                ControlFlow::Continue(())
+            }
+        }
+
+        let mut buf = BufferVisitor(Vec::new());
+        v.visit(&mut buf);
+        Ok(OwnedMap(buf.0.into_boxed_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn to_map() {
+        assert_eq!(
+            Some(vec![(Some(1u64), Some(true)), (Some(2u64), Some(false))]),
+            ValueBag::from_entries(&[(1u8, true), (2u8, false)])
+                .to_map::<u64, bool, Vec<(Option<u64>, Option<bool>)>>()
+        );
+
+        assert_eq!(
+            None,
+            ValueBag::from(1u64).to_map::<u64, bool, Vec<(Option<u64>, Option<bool>)>>()
+        );
+    }
+
+    #[cfg(feature = "owned")]
+    mod owned_support {
+        use super::*;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn to_map_owned() {
+            let v = ValueBag::from_entries(&[(1u8, true), (2u8, false)]).to_owned();
+
+            assert_eq!(
+                Some(vec![(Some(1u64), Some(true)), (Some(2u64), Some(false))]),
+                v.by_ref()
+                    .to_map::<u64, bool, Vec<(Option<u64>, Option<bool>)>>()
+            );
+
+            assert_eq!(
+                Some(vec![(Some(1u64), Some(true)), (Some(2u64), Some(false))]),
+                v.into_shared()
+                    .by_ref()
+                    .to_map::<u64, bool, Vec<(Option<u64>, Option<bool>)>>()
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod alloc_support {
+        use super::*;
+
+        use crate::std::string::String;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn btree_map_to_map() {
+            use crate::std::collections::BTreeMap;
+
+            let mut map = BTreeMap::new();
+            map.insert(String::from("a"), 1u64);
+            map.insert(String::from("b"), 2u64);
+
+            assert_eq!(
+                Some(vec![
+                    (Some(String::from("a")), Some(1u64)),
+                    (Some(String::from("b")), Some(2u64)),
+                ]),
+                ValueBag::from(&map).to_map::<String, u64, Vec<(Option<String>, Option<u64>)>>()
+            );
+
+            assert_eq!(
+                Some(
+                    map.iter()
+                        .map(|(k, v)| (Some(k.clone()), Some(*v)))
+                        .collect()
+                ),
+                ValueBag::from(&map).to_map::<String, u64, BTreeMap<Option<String>, Option<u64>>>()
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn hash_map_to_map() {
+            use crate::std::collections::{BTreeMap, HashMap};
+
+            let mut map = HashMap::new();
+            map.insert(String::from("a"), 1u64);
+            map.insert(String::from("b"), 2u64);
+
+            let mut expected = BTreeMap::new();
+            expected.insert(Some(String::from("a")), Some(1u64));
+            expected.insert(Some(String::from("b")), Some(2u64));
+
+            assert_eq!(
+                Some(expected),
+                ValueBag::from(&map).to_map::<String, u64, BTreeMap<Option<String>, Option<u64>>>()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn from_fields() {
+            use crate::std::collections::BTreeMap;
+
+            let fields = [
+                ("level", ValueBag::from("info")),
+                ("msg", ValueBag::from("a message")),
+            ];
+
+            let record = ValueBag::from_fields(&fields)
+                .to_map::<String, String, BTreeMap<Option<String>, Option<String>>>()
+                .unwrap();
+
+            assert_eq!(
+                Some(&Some(String::from("info"))),
+                record.get(&Some(String::from("level")))
+            );
+            assert_eq!(
+                Some(&Some(String::from("a message"))),
+                record.get(&Some(String::from("msg")))
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn from_labeled() {
+            let v = (("a", 1u64), ("b", 2u64));
+
+            assert_eq!(
+                Some(vec![
+                    (Some(String::from("a")), Some(1u64)),
+                    (Some(String::from("b")), Some(2u64)),
+                ]),
+                ValueBag::from_labeled(&v)
+                    .to_map::<String, u64, Vec<(Option<String>, Option<u64>)>>()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn entry_slice() {
+            let expected = Some(vec![
+                (Some(String::from("k")), Some(1u64)),
+                (Some(String::from("l")), Some(2u64)),
+            ]);
+
+            let v = [("k", 1u64), ("l", 2u64)];
+
+            assert_eq!(
+                expected,
+                ValueBag::from(&v).to_map::<String, u64, Vec<(Option<String>, Option<u64>)>>()
+            );
+            assert_eq!(
+                expected,
+                ValueBag::from(&&v[..]).to_map::<String, u64, Vec<(Option<String>, Option<u64>)>>()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn to_map_str_keys() {
+            let v = [("a", 1), ("b", 2)];
+            let v = ValueBag::from_entries(&v);
+
+            assert_eq!(
+                Some(vec![
+                    (Some(String::from("a")), Some(1i64)),
+                    (Some(String::from("b")), Some(2i64)),
+                ]),
+                v.to_map::<String, i64, Vec<(Option<String>, Option<i64>)>>()
+            );
+        }
+    }
+}
@@ -5,16 +5,34 @@
 
 use crate::{fill::Fill, Error, ValueBag};
 
+#[cfg(feature = "std")]
+pub(crate) mod any;
+#[cfg(feature = "base64")]
+pub(crate) mod base64;
 pub(crate) mod cast;
+#[cfg(any(feature = "time", feature = "chrono"))]
+pub(crate) mod datetime;
+#[cfg(feature = "rust_decimal")]
+pub(crate) mod decimal;
 #[cfg(feature = "error")]
 pub(crate) mod error;
 pub(crate) mod fmt;
+#[cfg(feature = "half")]
+pub(crate) mod half;
+pub(crate) mod intern;
+#[cfg(feature = "map")]
+pub(crate) mod map;
+#[cfg(any(feature = "std", value_bag_core_net))]
+pub(crate) mod net;
+#[cfg(feature = "std")]
+pub(crate) mod process;
 #[cfg(feature = "seq")]
 pub(crate) mod seq;
 #[cfg(feature = "serde1")]
 pub(crate) mod serde;
 #[cfg(feature = "sval2")]
 pub(crate) mod sval;
+pub(crate) mod time;
 
 #[cfg(feature = "owned")]
 pub(crate) mod owned;
@@ -43,10 +61,15 @@ pub(crate) enum Internal<'v> {
     Bool(bool),
     Char(char),
     Str(&'v str),
+    // Bytes that are lossily converted into a string when they're visited
+    #[cfg(feature = "alloc")]
+    LossyStr(&'v [u8]),
+    Bytes(&'v [u8]),
     None,
 
     // Captured values
     Fill(&'v dyn Fill),
+    Primitive(&'v dyn cast::primitive::DowncastPrimitive),
     Debug(&'v dyn fmt::DowncastDebug),
     Display(&'v dyn fmt::DowncastDisplay),
     #[cfg(feature = "error")]
@@ -67,6 +90,14 @@ pub(crate) enum Internal<'v> {
     AnonSerde1(&'v dyn serde::v1::Serialize),
     #[cfg(feature = "seq")]
     AnonSeq(&'v dyn seq::Seq),
+    #[cfg(feature = "map")]
+    AnonMap(&'v dyn map::Map),
+
+    // Flagged values
+    Flagged(&'v Internal<'v>),
+
+    // Formatted values paired with a structured value
+    ArgsWithValue(&'v crate::std::fmt::Arguments<'v>, &'v Internal<'v>),
 
     // Shared values
     #[cfg(feature = "owned")]
@@ -85,6 +116,8 @@ pub(crate) enum Internal<'v> {
     // NOTE: These variants exist because we can't clone an `Arc` in `const` fns
     // (plus we may not want to anyways)
     #[cfg(feature = "owned")]
+    SharedRefStr(&'v Arc<str>),
+    #[cfg(feature = "owned")]
     SharedRefDebug(&'v Arc<dyn fmt::DowncastDebug + Send + Sync>),
     #[cfg(feature = "owned")]
     SharedRefDisplay(&'v Arc<dyn fmt::DowncastDisplay + Send + Sync>),
@@ -96,6 +129,8 @@ pub(crate) enum Internal<'v> {
     SharedRefSval2(&'v Arc<dyn sval::v2::DowncastValue + Send + Sync>),
     #[cfg(all(feature = "seq", feature = "owned"))]
     SharedRefSeq(&'v Arc<dyn seq::DowncastSeq + Send + Sync>),
+    #[cfg(all(feature = "map", feature = "owned"))]
+    SharedRefMap(&'v Arc<dyn map::DowncastMap + Send + Sync>),
 
     // Poisoned value
     #[cfg_attr(not(feature = "owned"), allow(dead_code))]
@@ -146,6 +181,15 @@ pub(crate) trait InternalVisitor<'v> {
     fn borrowed_str(&mut self, v: &'v str) -> Result<(), Error> {
         self.str(v)
     }
+    #[cfg(feature = "owned")]
+    fn shared_str(&mut self, v: &'v Arc<str>) -> Result<(), Error> {
+        self.borrowed_str(v)
+    }
+
+    fn bytes(&mut self, v: &[u8]) -> Result<(), Error>;
+    fn borrowed_bytes(&mut self, v: &'v [u8]) -> Result<(), Error> {
+        self.bytes(v)
+    }
 
     fn none(&mut self) -> Result<(), Error>;
 
@@ -176,6 +220,11 @@ pub(crate) trait InternalVisitor<'v> {
     ) -> Result<(), Error> {
         self.sval2(v.as_super())
     }
+    #[cfg(feature = "sval2")]
+    fn sval2_tag(&mut self, tag: Option<&'static str>) -> Result<(), Error> {
+        let _ = tag;
+        Ok(())
+    }
 
     #[cfg(feature = "serde1")]
     fn serde1(&mut self, v: &dyn serde::v1::Serialize) -> Result<(), Error>;
@@ -204,10 +253,44 @@ pub(crate) trait InternalVisitor<'v> {
         self.seq(v.as_super())
     }
 
+    #[cfg(feature = "map")]
+    fn map(&mut self, v: &dyn map::Map) -> Result<(), Error>;
+
+    #[cfg(feature = "map")]
+    fn borrowed_map(&mut self, v: &'v dyn map::Map) -> Result<(), Error> {
+        self.map(v)
+    }
+
+    #[cfg(all(feature = "map", feature = "owned"))]
+    fn shared_map(&mut self, v: &Arc<dyn map::DowncastMap + Send + Sync>) -> Result<(), Error> {
+        self.map(v.as_super())
+    }
+
+    // Formatting visitors use the arguments, other visitors use the value
+    fn args_with_value(
+        &mut self,
+        args: &crate::std::fmt::Arguments,
+        value: &Internal<'v>,
+    ) -> Result<(), Error> {
+        let _ = args;
+        value.internal_visit(self)
+    }
+
+    // Like `args_with_value`, but the value is filled on demand, so it can be
+    // produced from data that doesn't live as long as the visitor
+    fn args_with_fill(
+        &mut self,
+        args: &crate::std::fmt::Arguments,
+        value: &dyn Fill,
+    ) -> Result<(), Error> {
+        let _ = args;
+        self.fill(value)
+    }
+
     fn poisoned(&mut self, msg: &'static str) -> Result<(), Error>;
 }
 
-impl<'a, 'v, V: InternalVisitor<'v> + ?Sized> InternalVisitor<'v> for &'a mut V {
+impl<'v, V: InternalVisitor<'v> + ?Sized> InternalVisitor<'v> for &mut V {
     fn fill(&mut self, v: &dyn Fill) -> Result<(), Error> {
         (**self).fill(v)
     }
@@ -287,6 +370,19 @@ impl<'a, 'v, V: InternalVisitor<'v> + ?Sized> InternalVisitor<'v> for &'a mut V
         (**self).borrowed_str(v)
     }
 
+    #[cfg(feature = "owned")]
+    fn shared_str(&mut self, v: &'v Arc<str>) -> Result<(), Error> {
+        (**self).shared_str(v)
+    }
+
+    fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+        (**self).bytes(v)
+    }
+
+    fn borrowed_bytes(&mut self, v: &'v [u8]) -> Result<(), Error> {
+        (**self).borrowed_bytes(v)
+    }
+
     fn none(&mut self) -> Result<(), Error> {
         (**self).none()
     }
@@ -327,6 +423,11 @@ impl<'a, 'v, V: InternalVisitor<'v> + ?Sized> InternalVisitor<'v> for &'a mut V
         (**self).shared_sval2(v)
     }
 
+    #[cfg(feature = "sval2")]
+    fn sval2_tag(&mut self, tag: Option<&'static str>) -> Result<(), Error> {
+        (**self).sval2_tag(tag)
+    }
+
     #[cfg(feature = "serde1")]
     fn serde1(&mut self, v: &dyn serde::v1::Serialize) -> Result<(), Error> {
         (**self).serde1(v)
@@ -360,11 +461,197 @@ impl<'a, 'v, V: InternalVisitor<'v> + ?Sized> InternalVisitor<'v> for &'a mut V
         (**self).shared_seq(seq)
     }
 
+    #[cfg(feature = "map")]
+    fn map(&mut self, map: &dyn map::Map) -> Result<(), Error> {
+        (**self).map(map)
+    }
+
+    #[cfg(feature = "map")]
+    fn borrowed_map(&mut self, map: &'v dyn map::Map) -> Result<(), Error> {
+        (**self).borrowed_map(map)
+    }
+
+    #[cfg(all(feature = "map", feature = "owned"))]
+    fn shared_map(&mut self, map: &Arc<dyn map::DowncastMap + Send + Sync>) -> Result<(), Error> {
+        (**self).shared_map(map)
+    }
+
+    fn args_with_value(
+        &mut self,
+        args: &crate::std::fmt::Arguments,
+        value: &Internal<'v>,
+    ) -> Result<(), Error> {
+        (**self).args_with_value(args, value)
+    }
+
+    fn args_with_fill(
+        &mut self,
+        args: &crate::std::fmt::Arguments,
+        value: &dyn Fill,
+    ) -> Result<(), Error> {
+        (**self).args_with_fill(args, value)
+    }
+
     fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
         (**self).poisoned(msg)
     }
 }
 
+/*
+Visits short-lived values, like those produced on demand while filling a slot,
+using a visitor that expects a longer lifetime. Borrowed values can't outlive the
+visitor's lifetime, so they're forwarded to their non-borrowed methods instead.
+*/
+pub(crate) struct Unborrowed<'a, 'b>(pub(crate) &'a mut dyn InternalVisitor<'b>);
+
+impl<'a, 'b, 'v> InternalVisitor<'v> for Unborrowed<'a, 'b> {
+    fn fill(&mut self, v: &dyn Fill) -> Result<(), Error> {
+        self.0.fill(v)
+    }
+
+    fn debug(&mut self, v: &dyn fmt::Debug) -> Result<(), Error> {
+        self.0.debug(v)
+    }
+
+    #[cfg(feature = "owned")]
+    fn shared_debug(&mut self, v: &Arc<dyn fmt::DowncastDebug + Send + Sync>) -> Result<(), Error> {
+        self.0.shared_debug(v)
+    }
+
+    fn display(&mut self, v: &dyn fmt::Display) -> Result<(), Error> {
+        self.0.display(v)
+    }
+
+    #[cfg(feature = "owned")]
+    fn shared_display(
+        &mut self,
+        v: &Arc<dyn fmt::DowncastDisplay + Send + Sync>,
+    ) -> Result<(), Error> {
+        self.0.shared_display(v)
+    }
+
+    fn u64(&mut self, v: u64) -> Result<(), Error> {
+        self.0.u64(v)
+    }
+
+    fn i64(&mut self, v: i64) -> Result<(), Error> {
+        self.0.i64(v)
+    }
+
+    fn u128(&mut self, v: &u128) -> Result<(), Error> {
+        self.0.u128(v)
+    }
+
+    fn i128(&mut self, v: &i128) -> Result<(), Error> {
+        self.0.i128(v)
+    }
+
+    fn f64(&mut self, v: f64) -> Result<(), Error> {
+        self.0.f64(v)
+    }
+
+    fn bool(&mut self, v: bool) -> Result<(), Error> {
+        self.0.bool(v)
+    }
+
+    fn char(&mut self, v: char) -> Result<(), Error> {
+        self.0.char(v)
+    }
+
+    fn str(&mut self, v: &str) -> Result<(), Error> {
+        self.0.str(v)
+    }
+
+    #[cfg(feature = "owned")]
+    fn shared_str(&mut self, v: &'v Arc<str>) -> Result<(), Error> {
+        self.0.str(v)
+    }
+
+    fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+        self.0.bytes(v)
+    }
+
+    fn none(&mut self) -> Result<(), Error> {
+        self.0.none()
+    }
+
+    #[cfg(feature = "error")]
+    fn error(&mut self, v: &(dyn error::Error + 'static)) -> Result<(), Error> {
+        self.0.error(v)
+    }
+
+    #[cfg(all(feature = "error", feature = "owned"))]
+    fn shared_error(
+        &mut self,
+        v: &Arc<dyn error::DowncastError + Send + Sync>,
+    ) -> Result<(), Error> {
+        self.0.shared_error(v)
+    }
+
+    #[cfg(feature = "sval2")]
+    fn sval2(&mut self, v: &dyn sval::v2::Value) -> Result<(), Error> {
+        self.0.sval2(v)
+    }
+
+    #[cfg(all(feature = "sval2", feature = "owned"))]
+    fn shared_sval2(
+        &mut self,
+        v: &Arc<dyn sval::v2::DowncastValue + Send + Sync>,
+    ) -> Result<(), Error> {
+        self.0.shared_sval2(v)
+    }
+
+    #[cfg(feature = "sval2")]
+    fn sval2_tag(&mut self, tag: Option<&'static str>) -> Result<(), Error> {
+        self.0.sval2_tag(tag)
+    }
+
+    #[cfg(feature = "serde1")]
+    fn serde1(&mut self, v: &dyn serde::v1::Serialize) -> Result<(), Error> {
+        self.0.serde1(v)
+    }
+
+    #[cfg(all(feature = "serde1", feature = "owned"))]
+    fn shared_serde1(
+        &mut self,
+        v: &Arc<dyn serde::v1::DowncastSerialize + Send + Sync>,
+    ) -> Result<(), Error> {
+        self.0.shared_serde1(v)
+    }
+
+    #[cfg(feature = "seq")]
+    fn seq(&mut self, seq: &dyn seq::Seq) -> Result<(), Error> {
+        self.0.seq(seq)
+    }
+
+    #[cfg(all(feature = "seq", feature = "owned"))]
+    fn shared_seq(&mut self, seq: &Arc<dyn seq::DowncastSeq + Send + Sync>) -> Result<(), Error> {
+        self.0.shared_seq(seq)
+    }
+
+    #[cfg(feature = "map")]
+    fn map(&mut self, map: &dyn map::Map) -> Result<(), Error> {
+        self.0.map(map)
+    }
+
+    #[cfg(all(feature = "map", feature = "owned"))]
+    fn shared_map(&mut self, map: &Arc<dyn map::DowncastMap + Send + Sync>) -> Result<(), Error> {
+        self.0.shared_map(map)
+    }
+
+    fn args_with_fill(
+        &mut self,
+        args: &crate::std::fmt::Arguments,
+        value: &dyn Fill,
+    ) -> Result<(), Error> {
+        self.0.args_with_fill(args, value)
+    }
+
+    fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
+        self.0.poisoned(msg)
+    }
+}
+
 impl<'v> ValueBag<'v> {
     /// Visit the value using an internal visitor.
     #[inline]
@@ -385,9 +672,13 @@ impl<'v> Internal<'v> {
             Internal::Bool(value) => Internal::Bool(*value),
             Internal::Char(value) => Internal::Char(*value),
             Internal::Str(value) => Internal::Str(value),
+            #[cfg(feature = "alloc")]
+            Internal::LossyStr(value) => Internal::LossyStr(value),
+            Internal::Bytes(value) => Internal::Bytes(value),
             Internal::None => Internal::None,
 
             Internal::Fill(value) => Internal::Fill(*value),
+            Internal::Primitive(value) => Internal::Primitive(*value),
 
             Internal::AnonDebug(value) => Internal::AnonDebug(*value),
             Internal::Debug(value) => Internal::Debug(*value),
@@ -413,6 +704,9 @@ impl<'v> Internal<'v> {
             #[cfg(feature = "seq")]
             Internal::AnonSeq(value) => Internal::AnonSeq(*value),
 
+            #[cfg(feature = "map")]
+            Internal::AnonMap(value) => Internal::AnonMap(*value),
+
             #[cfg(feature = "owned")]
             Internal::SharedDebug(ref value) => Internal::SharedRefDebug(value),
             #[cfg(feature = "owned")]
@@ -426,6 +720,8 @@ impl<'v> Internal<'v> {
             #[cfg(all(feature = "seq", feature = "owned"))]
             Internal::SharedSeq(ref value) => Internal::SharedRefSeq(value),
 
+            #[cfg(feature = "owned")]
+            Internal::SharedRefStr(value) => Internal::SharedRefStr(*value),
             #[cfg(feature = "owned")]
             Internal::SharedRefDebug(value) => Internal::SharedRefDebug(*value),
             #[cfg(feature = "owned")]
@@ -438,6 +734,12 @@ impl<'v> Internal<'v> {
             Internal::SharedRefSval2(value) => Internal::SharedRefSval2(*value),
             #[cfg(all(feature = "seq", feature = "owned"))]
             Internal::SharedRefSeq(value) => Internal::SharedRefSeq(*value),
+            #[cfg(all(feature = "map", feature = "owned"))]
+            Internal::SharedRefMap(value) => Internal::SharedRefMap(*value),
+
+            Internal::Flagged(value) => Internal::Flagged(*value),
+
+            Internal::ArgsWithValue(args, value) => Internal::ArgsWithValue(args, value),
 
             Internal::Poisoned(msg) => Internal::Poisoned(msg),
         }
@@ -463,9 +765,15 @@ impl<'v> Internal<'v> {
             Internal::Bool(value) => visitor.bool(*value),
             Internal::Char(value) => visitor.char(*value),
             Internal::Str(value) => visitor.borrowed_str(value),
+            #[cfg(feature = "alloc")]
+            Internal::LossyStr(value) => {
+                visitor.str(&crate::std::string::String::from_utf8_lossy(value))
+            }
+            Internal::Bytes(value) => visitor.borrowed_bytes(value),
             Internal::None => visitor.none(),
 
             Internal::Fill(value) => visitor.fill(*value),
+            Internal::Primitive(value) => (*value).to_internal().internal_visit(visitor),
 
             Internal::AnonDebug(value) => visitor.borrowed_debug(*value),
             Internal::Debug(value) => visitor.borrowed_debug(value.as_super()),
@@ -491,6 +799,9 @@ impl<'v> Internal<'v> {
             #[cfg(feature = "seq")]
             Internal::AnonSeq(value) => visitor.borrowed_seq(*value),
 
+            #[cfg(feature = "map")]
+            Internal::AnonMap(value) => visitor.borrowed_map(*value),
+
             #[cfg(feature = "owned")]
             Internal::SharedDebug(ref value) => visitor.shared_debug(value),
             #[cfg(feature = "owned")]
@@ -504,6 +815,8 @@ impl<'v> Internal<'v> {
             #[cfg(all(feature = "seq", feature = "owned"))]
             Internal::SharedSeq(value) => visitor.shared_seq(value),
 
+            #[cfg(feature = "owned")]
+            Internal::SharedRefStr(value) => visitor.shared_str(value),
             #[cfg(feature = "owned")]
             Internal::SharedRefDebug(value) => visitor.shared_debug(value),
             #[cfg(feature = "owned")]
@@ -516,6 +829,12 @@ impl<'v> Internal<'v> {
             Internal::SharedRefSval2(value) => visitor.shared_sval2(value),
             #[cfg(all(feature = "seq", feature = "owned"))]
             Internal::SharedRefSeq(value) => visitor.shared_seq(value),
+            #[cfg(all(feature = "map", feature = "owned"))]
+            Internal::SharedRefMap(value) => visitor.shared_map(value),
+
+            Internal::Flagged(value) => value.internal_visit(visitor),
+
+            Internal::ArgsWithValue(args, value) => visitor.args_with_value(args, value),
 
             Internal::Poisoned(msg) => visitor.poisoned(msg),
         }
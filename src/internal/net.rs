@@ -0,0 +1,86 @@
+//! Integration between `Value` and `core::net`.
+//!
+//! Socket addresses are captured using their `Display` implementation,
+//! so they're streamed as `host:port` strings without allocating.
+//!
+//! `core::net` is only available on Rust 1.77 or newer, so without `std`
+//! this module is only compiled when `build.rs` detects a new enough compiler.
+
+use crate::{std::net::SocketAddr, ValueBag};
+
+use super::Internal;
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a socket address.
+    ///
+    /// The address will be captured as a `host:port` string.
+    /// This method doesn't require the `std` feature on Rust 1.77 or newer.
+    pub const fn from_socket_addr(value: &'v SocketAddr) -> Self {
+        ValueBag {
+            inner: Internal::Display(value),
+        }
+    }
+}
+
+impl<'v> From<&'v SocketAddr> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v SocketAddr) -> Self {
+        ValueBag::from_socket_addr(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    use crate::std::{
+        fmt::{self, Write},
+        net::{Ipv4Addr, Ipv6Addr},
+    };
+
+    struct Buf {
+        buf: [u8; 64],
+        len: usize,
+    }
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+
+            Ok(())
+        }
+    }
+
+    fn format(value: ValueBag) -> Buf {
+        let mut buf = Buf {
+            buf: [0; 64],
+            len: 0,
+        };
+        write!(buf, "{}", value).unwrap();
+
+        buf
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn socket_addr_capture() {
+        let v4 = SocketAddr::from((Ipv4Addr::new(127, 0, 0, 1), 8080));
+        let v6 = SocketAddr::from((Ipv6Addr::LOCALHOST, 443));
+
+        let buf = format(ValueBag::from_socket_addr(&v4));
+        assert_eq!(b"127.0.0.1:8080", &buf.buf[..buf.len]);
+
+        let buf = format(ValueBag::from(&v6));
+        assert_eq!(b"[::1]:443", &buf.buf[..buf.len]);
+
+        assert_eq!(Some(&v4), ValueBag::from(&v4).downcast_ref::<SocketAddr>());
+    }
+}
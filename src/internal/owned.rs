@@ -7,27 +7,39 @@ use crate::{
 #[derive(Clone)]
 pub(crate) enum OwnedInternal {
     // Primitive values
-    BigSigned(i128),
-    BigUnsigned(u128),
+    Signed(i64),
+    Unsigned(u64),
     Float(f64),
     Bool(bool),
     Char(char),
     Str(Box<str>),
+    Bytes(Box<[u8]>),
     None,
 
+    // Rarely used values
+    //
+    // These are boxed so they don't grow the size of the
+    // more common variants
+    BigSigned(Box<i128>),
+    BigUnsigned(Box<u128>),
+
     // Buffered values
     Debug(internal::fmt::owned::OwnedFmt),
     Display(internal::fmt::owned::OwnedFmt),
+    // Errors and `sval` buffers are boxed for the same reason as big numbers
     #[cfg(feature = "error")]
-    Error(internal::error::owned::OwnedError),
+    Error(Box<internal::error::owned::OwnedError>),
     #[cfg(feature = "serde1")]
     Serde1(internal::serde::v1::owned::OwnedSerialize),
     #[cfg(feature = "sval2")]
-    Sval2(internal::sval::v2::owned::OwnedValue),
+    Sval2(Box<internal::sval::v2::owned::OwnedValue>),
     #[cfg(feature = "seq")]
     Seq(internal::seq::owned::OwnedSeq),
+    #[cfg(feature = "map")]
+    Map(internal::map::owned::OwnedMap),
 
     // Shared values
+    SharedStr(Arc<str>),
     SharedDebug(Arc<dyn internal::fmt::DowncastDebug + Send + Sync>),
     SharedDisplay(Arc<dyn internal::fmt::DowncastDisplay + Send + Sync>),
     #[cfg(feature = "error")]
@@ -38,6 +50,8 @@ pub(crate) enum OwnedInternal {
     SharedSval2(Arc<dyn internal::sval::v2::DowncastValue + Send + Sync>),
     #[cfg(feature = "seq")]
     SharedSeq(Arc<dyn internal::seq::DowncastSeq + Send + Sync>),
+    #[cfg(feature = "map")]
+    SharedMap(Arc<dyn internal::map::DowncastMap + Send + Sync>),
 
     // Poisoned value
     Poisoned(&'static str),
@@ -45,33 +59,39 @@ pub(crate) enum OwnedInternal {
 
 impl OwnedInternal {
     #[inline]
-    pub(crate) const fn by_ref(&self) -> Internal {
+    pub(crate) const fn by_ref(&self) -> Internal<'_> {
         match self {
+            OwnedInternal::Signed(v) => Internal::Signed(*v),
+            OwnedInternal::Unsigned(v) => Internal::Unsigned(*v),
             #[cfg(not(feature = "inline-i128"))]
             OwnedInternal::BigSigned(v) => Internal::BigSigned(v),
             #[cfg(feature = "inline-i128")]
-            OwnedInternal::BigSigned(v) => Internal::BigSigned(*v),
+            OwnedInternal::BigSigned(v) => Internal::BigSigned(**v),
             #[cfg(not(feature = "inline-i128"))]
             OwnedInternal::BigUnsigned(v) => Internal::BigUnsigned(v),
             #[cfg(feature = "inline-i128")]
-            OwnedInternal::BigUnsigned(v) => Internal::BigUnsigned(*v),
+            OwnedInternal::BigUnsigned(v) => Internal::BigUnsigned(**v),
             OwnedInternal::Float(v) => Internal::Float(*v),
             OwnedInternal::Bool(v) => Internal::Bool(*v),
             OwnedInternal::Char(v) => Internal::Char(*v),
             OwnedInternal::Str(v) => Internal::Str(v),
+            OwnedInternal::Bytes(v) => Internal::Bytes(v),
             OwnedInternal::None => Internal::None,
 
             OwnedInternal::Debug(v) => Internal::AnonDebug(v),
             OwnedInternal::Display(v) => Internal::AnonDisplay(v),
             #[cfg(feature = "error")]
-            OwnedInternal::Error(v) => Internal::AnonError(v),
+            OwnedInternal::Error(v) => Internal::AnonError(&**v),
             #[cfg(feature = "serde1")]
             OwnedInternal::Serde1(v) => Internal::AnonSerde1(v),
             #[cfg(feature = "sval2")]
-            OwnedInternal::Sval2(v) => Internal::AnonSval2(v),
+            OwnedInternal::Sval2(v) => Internal::AnonSval2(&**v),
             #[cfg(feature = "seq")]
             OwnedInternal::Seq(v) => Internal::AnonSeq(v),
+            #[cfg(feature = "map")]
+            OwnedInternal::Map(v) => Internal::AnonMap(v),
 
+            OwnedInternal::SharedStr(ref value) => Internal::SharedRefStr(value),
             OwnedInternal::SharedDebug(ref value) => Internal::SharedRefDebug(value),
             OwnedInternal::SharedDisplay(ref value) => Internal::SharedRefDisplay(value),
             #[cfg(feature = "error")]
@@ -82,6 +102,8 @@ impl OwnedInternal {
             OwnedInternal::SharedSval2(ref value) => Internal::SharedRefSval2(value),
             #[cfg(feature = "seq")]
             OwnedInternal::SharedSeq(ref value) => Internal::SharedRefSeq(value),
+            #[cfg(feature = "map")]
+            OwnedInternal::SharedMap(ref value) => Internal::SharedRefMap(value),
 
             OwnedInternal::Poisoned(msg) => Internal::Poisoned(msg),
         }
@@ -90,25 +112,31 @@ impl OwnedInternal {
     #[inline]
     pub(crate) fn into_shared(self) -> Self {
         match self {
+            OwnedInternal::Signed(v) => OwnedInternal::Signed(v),
+            OwnedInternal::Unsigned(v) => OwnedInternal::Unsigned(v),
             OwnedInternal::BigSigned(v) => OwnedInternal::BigSigned(v),
             OwnedInternal::BigUnsigned(v) => OwnedInternal::BigUnsigned(v),
             OwnedInternal::Float(v) => OwnedInternal::Float(v),
             OwnedInternal::Bool(v) => OwnedInternal::Bool(v),
             OwnedInternal::Char(v) => OwnedInternal::Char(v),
             OwnedInternal::Str(v) => OwnedInternal::Str(v),
+            OwnedInternal::Bytes(v) => OwnedInternal::Bytes(v),
             OwnedInternal::None => OwnedInternal::None,
 
             OwnedInternal::Debug(v) => OwnedInternal::SharedDebug(Arc::new(v)),
             OwnedInternal::Display(v) => OwnedInternal::SharedDisplay(Arc::new(v)),
             #[cfg(feature = "error")]
-            OwnedInternal::Error(v) => OwnedInternal::SharedError(Arc::new(v)),
+            OwnedInternal::Error(v) => OwnedInternal::SharedError(Arc::new(*v)),
             #[cfg(feature = "serde1")]
             OwnedInternal::Serde1(v) => OwnedInternal::SharedSerde1(Arc::new(v)),
             #[cfg(feature = "sval2")]
-            OwnedInternal::Sval2(v) => OwnedInternal::SharedSval2(Arc::new(v)),
+            OwnedInternal::Sval2(v) => OwnedInternal::SharedSval2(Arc::new(*v)),
             #[cfg(feature = "seq")]
             OwnedInternal::Seq(v) => OwnedInternal::SharedSeq(Arc::new(v)),
+            #[cfg(feature = "map")]
+            OwnedInternal::Map(v) => OwnedInternal::SharedMap(Arc::new(v)),
 
+            OwnedInternal::SharedStr(v) => OwnedInternal::SharedStr(v),
             OwnedInternal::SharedDebug(v) => OwnedInternal::SharedDebug(v),
             OwnedInternal::SharedDisplay(v) => OwnedInternal::SharedDisplay(v),
             #[cfg(feature = "error")]
@@ -119,6 +147,8 @@ impl OwnedInternal {
             OwnedInternal::SharedSval2(v) => OwnedInternal::SharedSval2(v),
             #[cfg(feature = "seq")]
             OwnedInternal::SharedSeq(v) => OwnedInternal::SharedSeq(v),
+            #[cfg(feature = "map")]
+            OwnedInternal::SharedMap(v) => OwnedInternal::SharedMap(v),
 
             OwnedInternal::Poisoned(msg) => OwnedInternal::Poisoned(msg),
         }
@@ -161,22 +191,22 @@ impl<'v> Internal<'v> {
             }
 
             fn u64(&mut self, v: u64) -> Result<(), Error> {
-                self.0 = OwnedInternal::BigUnsigned(v as u128);
+                self.0 = OwnedInternal::Unsigned(v);
                 Ok(())
             }
 
             fn i64(&mut self, v: i64) -> Result<(), Error> {
-                self.0 = OwnedInternal::BigSigned(v as i128);
+                self.0 = OwnedInternal::Signed(v);
                 Ok(())
             }
 
             fn u128(&mut self, v: &u128) -> Result<(), Error> {
-                self.0 = OwnedInternal::BigUnsigned(*v);
+                self.0 = OwnedInternal::BigUnsigned(Box::new(*v));
                 Ok(())
             }
 
             fn i128(&mut self, v: &i128) -> Result<(), Error> {
-                self.0 = OwnedInternal::BigSigned(*v);
+                self.0 = OwnedInternal::BigSigned(Box::new(*v));
                 Ok(())
             }
 
@@ -200,6 +230,16 @@ impl<'v> Internal<'v> {
                 Ok(())
             }
 
+            fn shared_str(&mut self, v: &Arc<str>) -> Result<(), Error> {
+                self.0 = OwnedInternal::SharedStr(v.clone());
+                Ok(())
+            }
+
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                self.0 = OwnedInternal::Bytes(v.into());
+                Ok(())
+            }
+
             fn none(&mut self) -> Result<(), Error> {
                 self.0 = OwnedInternal::None;
                 Ok(())
@@ -207,7 +247,7 @@ impl<'v> Internal<'v> {
 
             #[cfg(feature = "error")]
             fn error(&mut self, v: &(dyn internal::error::Error + 'static)) -> Result<(), Error> {
-                self.0 = OwnedInternal::Error(internal::error::owned::buffer(v));
+                self.0 = OwnedInternal::Error(Box::new(internal::error::owned::buffer(v)));
                 Ok(())
             }
 
@@ -223,7 +263,7 @@ impl<'v> Internal<'v> {
             #[cfg(feature = "sval2")]
             fn sval2(&mut self, v: &dyn internal::sval::v2::Value) -> Result<(), Error> {
                 self.0 = internal::sval::v2::owned::buffer(v)
-                    .map(OwnedInternal::Sval2)
+                    .map(|v| OwnedInternal::Sval2(Box::new(v)))
                     .unwrap_or(OwnedInternal::Poisoned("failed to buffer the value"));
                 Ok(())
             }
@@ -271,6 +311,23 @@ impl<'v> Internal<'v> {
                 Ok(())
             }
 
+            #[cfg(feature = "map")]
+            fn map(&mut self, v: &dyn internal::map::Map) -> Result<(), Error> {
+                self.0 = internal::map::owned::buffer(v)
+                    .map(OwnedInternal::Map)
+                    .unwrap_or(OwnedInternal::Poisoned("failed to buffer the value"));
+                Ok(())
+            }
+
+            #[cfg(feature = "map")]
+            fn shared_map(
+                &mut self,
+                v: &Arc<dyn internal::map::DowncastMap + Send + Sync>,
+            ) -> Result<(), Error> {
+                self.0 = OwnedInternal::SharedMap(v.clone());
+                Ok(())
+            }
+
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
                 self.0 = OwnedInternal::Poisoned(msg);
                 Ok(())
@@ -0,0 +1,108 @@
+//! Integration between `Value` and `std::process`.
+//!
+//! Exit statuses are captured as their exit code when there is one.
+//! On Unix, statuses for processes terminated by a signal are captured as
+//! the signal number, formatted using their `Display` implementation.
+
+use crate::{
+    fill::{Fill, Slot},
+    std::process::ExitStatus,
+    Error, ValueBag,
+};
+
+use super::Internal;
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from the exit status of a process.
+    ///
+    /// If the process exited with a code then it will be captured as a number.
+    /// If the process was terminated by a signal on Unix then it will be captured
+    /// as the signal number, and formatted using the status' `Display` implementation.
+    /// Otherwise, the status will be captured using its `Display` implementation.
+    pub const fn from_exit_status(value: &'v ExitStatus) -> Self {
+        ValueBag {
+            inner: Internal::Fill(ExitStatusCode::new_ref(value)),
+        }
+    }
+}
+
+#[repr(transparent)]
+struct ExitStatusCode(ExitStatus);
+
+impl ExitStatusCode {
+    const fn new_ref(v: &ExitStatus) -> &ExitStatusCode {
+        // SAFETY: `ExitStatusCode` and `ExitStatus` have the same ABI
+        unsafe { &*(v as *const ExitStatus as *const ExitStatusCode) }
+    }
+}
+
+impl Fill for ExitStatusCode {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        if let Some(code) = self.0.code() {
+            return slot.fill(|visitor| visitor.i64(code.into()));
+        }
+
+        #[cfg(unix)]
+        {
+            use crate::std::os::unix::process::ExitStatusExt;
+
+            if let Some(signal) = self.0.signal() {
+                return slot.fill(|visitor| {
+                    visitor.args_with_value(
+                        &format_args!("{}", self.0),
+                        &Internal::Signed(signal.into()),
+                    )
+                });
+            }
+        }
+
+        slot.fill(|visitor| visitor.display(&self.0))
+    }
+}
+
+impl<'v> From<&'v ExitStatus> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v ExitStatus) -> Self {
+        ValueBag::from_exit_status(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    use crate::std::{os::unix::process::ExitStatusExt, string::ToString};
+
+    #[cfg(windows)]
+    use crate::std::os::windows::process::ExitStatusExt;
+
+    #[test]
+    #[cfg(unix)]
+    fn exit_status_code() {
+        let status = ExitStatus::from_raw(1 << 8);
+
+        assert_eq!(Some(1), ValueBag::from_exit_status(&status).to_i64());
+        assert_eq!(Some(1), ValueBag::from(&status).to_i64());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn exit_status_signal() {
+        let status = ExitStatus::from_raw(9);
+
+        let value = ValueBag::from_exit_status(&status);
+
+        assert_eq!(Some(9), value.to_i64());
+        assert_eq!(status.to_string(), value.to_string());
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn exit_status_code() {
+        let status = ExitStatus::from_raw(1);
+
+        assert_eq!(Some(1), ValueBag::from_exit_status(&status).to_i64());
+        assert_eq!(Some(1), ValueBag::from(&status).to_i64());
+    }
+}
@@ -1,10 +1,24 @@
 use crate::{
     fill::Slot,
+    impls::Element,
     internal::{Internal, InternalVisitor},
-    std::{any::Any, fmt, marker::PhantomData, mem, ops::ControlFlow},
+    std::{
+        any::Any,
+        fmt,
+        marker::PhantomData,
+        mem,
+        num::{
+            NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+            NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+        },
+        ops::ControlFlow,
+    },
     Error, ValueBag,
 };
 
+#[cfg(value_bag_saturating)]
+use crate::std::num::Saturating;
+
 #[cfg(feature = "alloc")]
 use crate::std::{string::String, vec::Vec};
 
@@ -20,6 +34,56 @@ impl<'v> ValueBag<'v> {
         }
     }
 
+    /// Get a value from a sequence of optional values without capturing support.
+    ///
+    /// Elements that are `None` will be captured as empty values.
+    pub fn from_opt_seq_slice<I, T>(value: &'v I) -> Self
+    where
+        I: AsRef<[Option<T>]>,
+        &'v T: Into<ValueBag<'v>> + 'v,
+    {
+        ValueBag {
+            inner: Internal::AnonSeq(OptSeqSlice::new_ref(value)),
+        }
+    }
+
+    /// Get a value from a sequence of displayable values.
+    ///
+    /// Each element will be captured using [`ValueBag::from_dyn_display`].
+    pub fn from_display_slice<I>(value: &'v I) -> Self
+    where
+        I: AsRef<[&'v dyn fmt::Display]>,
+    {
+        ValueBag {
+            inner: Internal::AnonSeq(SeqSlice::<I, &'v dyn fmt::Display>::new_ref(value)),
+        }
+    }
+
+    /// Lazily transform the elements of this value as a sequence.
+    ///
+    /// The function `f` is called on each element whenever the returned [`MapSeq`]
+    /// is inspected, so it may be called more than once for the same element.
+    /// Use [`MapSeq::by_ref`] to get a `ValueBag` for the transformed sequence.
+    ///
+    /// If this value is not a sequence then the transformed sequence will be empty.
+    pub fn map_seq<F>(self, f: F) -> MapSeq<'v, F>
+    where
+        F: Fn(ValueBag) -> ValueBag,
+    {
+        MapSeq { value: self, f }
+    }
+
+    /// Get a value from an array of `bool`s that's displayed as a string of bits.
+    ///
+    /// The value will format using `Display` as a string like `101`, with a `1`
+    /// for each `true` and a `0` for each `false`. It can still be inspected as
+    /// a sequence of `bool`s, such as through [`ValueBag::to_bool_seq`].
+    pub const fn from_bool_array_as_bits<const N: usize>(value: &'v [bool; N]) -> Self {
+        ValueBag {
+            inner: Internal::AnonSeq(BoolBits::new_ref(value)),
+        }
+    }
+
     pub(crate) const fn from_dyn_seq(value: &'v dyn Seq) -> Self {
         ValueBag {
             inner: Internal::AnonSeq(value),
@@ -86,6 +150,32 @@ impl<'v> ValueBag<'v> {
             .map(|seq| seq.into_inner())
     }
 
+    /// Try get a collection `S` of `f64`s from this value, parsing elements that are strings.
+    ///
+    /// This method is like [`ValueBag::to_f64_seq`], except elements that are strings
+    /// will also be parsed as `f64`s. Elements that aren't numbers and don't parse as
+    /// numbers will be `None`.
+    ///
+    /// If this value is not a sequence then this method will return `None`.
+    pub fn parse_f64_seq<S: Default + Extend<Option<f64>>>(&self) -> Option<S> {
+        #[derive(Default)]
+        struct ParseF64<S>(S);
+
+        impl<'a, S: Extend<Option<f64>>> ExtendValue<'a> for ParseF64<S> {
+            fn extend(&mut self, inner: Internal<'_>) {
+                let value = ValueBag { inner };
+
+                self.0.extend(Some(
+                    value
+                        .to_f64()
+                        .or_else(|| value.to_borrowed_str()?.parse().ok()),
+                ))
+            }
+        }
+
+        self.inner.extend::<ParseF64<S>>().map(|seq| seq.0)
+    }
+
     /// Get a collection `S` of `f64`s from this value.
     ///
     /// If this value is a sequence then the collection `S` will be extended
@@ -114,6 +204,31 @@ impl<'v> ValueBag<'v> {
             .unwrap_or_default()
     }
 
+    /// Get a collection `S` of `f32`s from this value.
+    ///
+    /// If this value is a sequence then the collection `S` will be extended
+    /// with the conversion of each of its elements. The conversion is the
+    /// same as [`ValueBag::as_f64`], narrowed to an `f32`. Values that
+    /// can't be represented exactly as an `f32` will be rounded.
+    ///
+    /// If this value is not a sequence then this method will return an
+    /// empty collection.
+    pub fn as_f32_seq<S: Default + Extend<f32>>(&self) -> S {
+        #[derive(Default)]
+        struct ExtendF32<S>(S);
+
+        impl<'a, S: Extend<f32>> ExtendValue<'a> for ExtendF32<S> {
+            fn extend(&mut self, inner: Internal<'_>) {
+                self.0.extend(Some(ValueBag { inner }.as_f64() as f32))
+            }
+        }
+
+        self.inner
+            .extend::<ExtendF32<S>>()
+            .map(|seq| seq.0)
+            .unwrap_or_default()
+    }
+
     /// Try get a collection `S` of `bool`s from this value.
     ///
     /// If this value is a sequence then the collection `S` will be extended
@@ -161,6 +276,160 @@ impl<'v> ValueBag<'v> {
 
         self.inner.extend::<ExtendStr<'v, S>>().map(|seq| seq.0)
     }
+
+    /// Try get the number of elements in this value.
+    ///
+    /// If this value is a sequence then its elements will be counted
+    /// without collecting them.
+    ///
+    /// If this value is not a sequence then this method will return `None`.
+    pub fn count_seq(&self) -> Option<usize> {
+        #[derive(Default)]
+        struct Count(usize);
+
+        impl<'a> ExtendValue<'a> for Count {
+            fn extend(&mut self, _: Internal<'_>) {
+                self.0 += 1;
+            }
+        }
+
+        self.inner.extend::<Count>().map(|seq| seq.0)
+    }
+
+    /// Try get the element at `index` from this value.
+    ///
+    /// If this value is a sequence then its elements will be visited up to
+    /// `index`, so this method is O(n) in the length of the sequence.
+    ///
+    /// Elements that are only available for a short lifetime can only be
+    /// returned if they don't borrow any data, in the same way as
+    /// [`ValueBag::try_into_static`].
+    ///
+    /// If this value is not a sequence, or `index` is out of bounds, then
+    /// this method will return `None`.
+    pub fn seq_get(&self, index: usize) -> Option<ValueBag<'v>> {
+        struct Get<'a> {
+            remaining: Option<usize>,
+            value: Option<ValueBag<'a>>,
+        }
+
+        impl<'a> Get<'a> {
+            fn next(&mut self) -> bool {
+                match self.remaining {
+                    Some(0) => {
+                        self.remaining = None;
+                        true
+                    }
+                    Some(ref mut remaining) => {
+                        *remaining -= 1;
+                        false
+                    }
+                    None => false,
+                }
+            }
+        }
+
+        impl<'a> ExtendValue<'a> for Get<'a> {
+            fn extend(&mut self, inner: Internal<'_>) {
+                if self.next() {
+                    self.value = ValueBag { inner }.try_into_static().ok();
+                }
+            }
+
+            fn extend_borrowed(&mut self, inner: Internal<'a>) {
+                if self.next() {
+                    self.value = Some(ValueBag { inner });
+                }
+            }
+        }
+
+        self.inner
+            .extend_from(Get {
+                remaining: Some(index),
+                value: None,
+            })
+            .and_then(|seq| seq.value)
+    }
+
+    /// Fold over the elements of this value, accumulating a result.
+    ///
+    /// If this value is a sequence then `f` will be called for each of its elements.
+    /// If this value is not a sequence then `f` will be called once with the value itself.
+    pub fn fold<R>(&self, init: R, mut f: impl FnMut(R, ValueBag) -> R) -> R {
+        struct Fold<'a, R, F> {
+            // The accumulator is only taken while `f` is running
+            acc: Option<R>,
+            f: &'a mut F,
+        }
+
+        impl<'a, R, F: FnMut(R, ValueBag) -> R> Fold<'a, R, F> {
+            fn push(&mut self, value: ValueBag) {
+                self.acc = self.acc.take().map(|acc| (self.f)(acc, value));
+            }
+        }
+
+        impl<'a, 'b, 'v, R, F: FnMut(R, ValueBag) -> R> ExtendValue<'v> for &'b mut Fold<'a, R, F> {
+            fn extend(&mut self, inner: Internal<'_>) {
+                self.push(ValueBag { inner });
+            }
+        }
+
+        let mut fold = Fold {
+            acc: Some(init),
+            f: &mut f,
+        };
+
+        // Values that aren't sequences are folded as a single element
+        if self.inner.extend_from(&mut fold).is_none() {
+            fold.push(self.by_ref());
+        }
+
+        match fold.acc {
+            Some(acc) => acc,
+            // The accumulator is put back as soon as `f` returns, so it can
+            // only be missing if `f` panicked, which unwinds past this point
+            None => unreachable!(),
+        }
+    }
+
+    /// Try get the first value that isn't empty.
+    ///
+    /// If this value is a sequence then this method will return its first
+    /// element that isn't empty. Elements that are only available for a
+    /// short lifetime can only be returned if they don't borrow any data,
+    /// in the same way as [`ValueBag::try_into_static`], so any others are
+    /// skipped.
+    ///
+    /// If this value is not a sequence then it will be returned as-is,
+    /// unless it's empty.
+    pub fn first_some(&self) -> Option<ValueBag<'v>> {
+        #[derive(Default)]
+        struct First<'a>(Option<ValueBag<'a>>);
+
+        impl<'a> ExtendValue<'a> for First<'a> {
+            fn extend(&mut self, inner: Internal<'_>) {
+                let value = ValueBag { inner };
+
+                if self.0.is_none() && !value.is_empty() {
+                    self.0 = value.try_into_static().ok();
+                }
+            }
+
+            fn extend_borrowed(&mut self, inner: Internal<'a>) {
+                let value = ValueBag { inner };
+
+                if self.0.is_none() && !value.is_empty() {
+                    self.0 = Some(value);
+                }
+            }
+        }
+
+        match self.inner.extend::<First<'v>>() {
+            Some(seq) => seq.0,
+            None if self.is_empty() => None,
+            None => Some(self.clone()),
+        }
+    }
 }
 
 impl<'s, 'f> Slot<'s, 'f> {
@@ -222,15 +491,94 @@ where
     }
 }
 
+#[repr(transparent)]
+struct OptSeqSlice<'a, I: ?Sized, T>(PhantomData<&'a [Option<T>]>, I);
+
+impl<'a, I: AsRef<[Option<T>]> + ?Sized + 'a, T> OptSeqSlice<'a, I, T> {
+    fn new_ref(v: &'a I) -> &'a OptSeqSlice<'a, I, T> {
+        // SAFETY: `OptSeqSlice<'a, I, T>` and `I` have the same ABI
+        unsafe { &*(v as *const I as *const OptSeqSlice<'a, I, T>) }
+    }
+
+    fn as_ref<'b>(&'b self) -> &'a [Option<T>] {
+        // SAFETY: `new_ref` requires there's a borrow of `&'a I`
+        // on the borrow stack, so we can safely borrow it for `'a` here
+        let inner = unsafe { mem::transmute::<&'b I, &'a I>(&self.1) };
+
+        inner.as_ref()
+    }
+}
+
+impl<'a, I, T> Seq for OptSeqSlice<'a, I, T>
+where
+    I: AsRef<[Option<T>]> + ?Sized + 'a,
+    &'a T: Into<ValueBag<'a>>,
+{
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        for v in self.as_ref().iter() {
+            if let ControlFlow::Break(()) = visitor.element(ValueBag::from_option(v.as_ref())) {
+                return;
+            }
+        }
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        for v in self.as_ref().iter() {
+            if let ControlFlow::Break(()) =
+                visitor.borrowed_element(ValueBag::from_option(v.as_ref()))
+            {
+                return;
+            }
+        }
+    }
+}
+
+// Slices, arrays, and `Vec`s of `Element` types are sequences themselves,
+// so they can be captured without wrapping them, see `impls::Element`
+impl<T: Element> Seq for [T] {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        for v in self {
+            if let ControlFlow::Break(()) = visitor.element(v.to_value_bag()) {
+                return;
+            }
+        }
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        for v in self {
+            if let ControlFlow::Break(()) = visitor.borrowed_element(v.to_value_bag()) {
+                return;
+            }
+        }
+    }
+}
+
 pub(crate) trait Seq {
     fn visit(&self, visitor: &mut dyn Visitor<'_>);
 
     fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
         self.visit(visitor)
     }
+
+    // Sequences can override how they're formatted using `Display`
+    // If this method returns `None` then the sequence is formatted as a list
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> Option<fmt::Result> {
+        let _ = f;
+        None
+    }
+}
+
+impl<T: Element, const N: usize> Seq for [T; N] {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        self.as_slice().visit(visitor)
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        self.as_slice().borrowed_visit(visitor)
+    }
 }
 
-impl<'a, S: Seq + ?Sized> Seq for &'a S {
+impl<S: Seq + ?Sized> Seq for &S {
     fn visit(&self, visitor: &mut dyn Visitor<'_>) {
         (**self).visit(visitor)
     }
@@ -238,6 +586,10 @@ impl<'a, S: Seq + ?Sized> Seq for &'a S {
     fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
         (**self).borrowed_visit(visitor)
     }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> Option<fmt::Result> {
+        (**self).fmt_display(f)
+    }
 }
 
 pub(crate) trait Visitor<'v> {
@@ -248,7 +600,7 @@ pub(crate) trait Visitor<'v> {
     }
 }
 
-impl<'a, 'v, T: Visitor<'v> + ?Sized> Visitor<'v> for &'a mut T {
+impl<'v, T: Visitor<'v> + ?Sized> Visitor<'v> for &mut T {
     fn element(&mut self, v: ValueBag) -> ControlFlow<()> {
         (**self).element(v)
     }
@@ -329,6 +681,92 @@ convert_primitive![
     u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
 ];
 
+macro_rules! convert_wrapping(
+    ($($t:ty,)*) => {
+        $(
+            impl<'v, const N: usize> From<&'v [Wrapping<$t>; N]> for ValueBag<'v> {
+                fn from(v: &'v [Wrapping<$t>; N]) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            impl<'a, 'v> From<&'v &'a [Wrapping<$t>]> for ValueBag<'v> {
+                fn from(v: &'v &'a [Wrapping<$t>]) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'v> From<&'v Vec<Wrapping<$t>>> for ValueBag<'v> {
+                fn from(v: &'v Vec<Wrapping<$t>>) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            #[cfg(value_bag_saturating)]
+            impl<'v, const N: usize> From<&'v [Saturating<$t>; N]> for ValueBag<'v> {
+                fn from(v: &'v [Saturating<$t>; N]) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            #[cfg(value_bag_saturating)]
+            impl<'a, 'v> From<&'v &'a [Saturating<$t>]> for ValueBag<'v> {
+                fn from(v: &'v &'a [Saturating<$t>]) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            #[cfg(all(feature = "alloc", value_bag_saturating))]
+            impl<'v> From<&'v Vec<Saturating<$t>>> for ValueBag<'v> {
+                fn from(v: &'v Vec<Saturating<$t>>) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+        )*
+    }
+);
+
+convert_wrapping![u8, u16, u32, u64, usize, i8, i16, i32, i64, isize,];
+
+macro_rules! convert_non_zero(
+    ($($t:ty,)*) => {
+        $(
+            impl<'v, const N: usize> From<&'v [$t; N]> for ValueBag<'v> {
+                fn from(v: &'v [$t; N]) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            impl<'a, 'v> From<&'v &'a [$t]> for ValueBag<'v> {
+                fn from(v: &'v &'a [$t]) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'v> From<&'v Vec<$t>> for ValueBag<'v> {
+                fn from(v: &'v Vec<$t>) -> Self {
+                    ValueBag::from_seq_slice(v)
+                }
+            }
+        )*
+    }
+);
+
+convert_non_zero![
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize,
+];
+
 impl<'v, 'a, const N: usize> From<&'v [&'a str; N]> for ValueBag<'v> {
     fn from(v: &'v [&'a str; N]) -> Self {
         ValueBag::from_seq_slice(v)
@@ -353,6 +791,44 @@ impl<'v, 'a, 'b> From<Option<&'v &'a [&'b str]>> for ValueBag<'v> {
     }
 }
 
+impl<'v, 'a, const N: usize> From<&'v [Option<&'a str>; N]> for ValueBag<'v> {
+    fn from(v: &'v [Option<&'a str>; N]) -> Self {
+        ValueBag::from_opt_seq_slice(v)
+    }
+}
+
+impl<'v, 'a, 'b> From<&'v &'a [Option<&'b str>]> for ValueBag<'v> {
+    fn from(v: &'v &'a [Option<&'b str>]) -> Self {
+        ValueBag::from_opt_seq_slice(v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'v, 'a> From<&'v Vec<Option<&'a str>>> for ValueBag<'v> {
+    fn from(v: &'v Vec<Option<&'a str>>) -> Self {
+        ValueBag::from_opt_seq_slice(v)
+    }
+}
+
+impl<'v, 'a, const N: usize> From<&'v [ValueBag<'a>; N]> for ValueBag<'v> {
+    fn from(v: &'v [ValueBag<'a>; N]) -> Self {
+        ValueBag::from_seq_slice(v)
+    }
+}
+
+impl<'v, 'a, 'b> From<&'v &'a [ValueBag<'b>]> for ValueBag<'v> {
+    fn from(v: &'v &'a [ValueBag<'b>]) -> Self {
+        ValueBag::from_seq_slice(v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'v, 'a> From<&'v Vec<ValueBag<'a>>> for ValueBag<'v> {
+    fn from(v: &'v Vec<ValueBag<'a>>) -> Self {
+        ValueBag::from_seq_slice(v)
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<'v> From<&'v Vec<String>> for ValueBag<'v> {
     fn from(v: &'v Vec<String>) -> Self {
@@ -367,7 +843,129 @@ impl<'v> From<Option<&'v Vec<String>>> for ValueBag<'v> {
     }
 }
 
-#[derive(Default)]
+// Tuple elements are limited to `Element` types, see `impls::Element` for why
+macro_rules! convert_tuple(
+    ($(($($i:tt $t:ident),+),)*) => {
+        $(
+            impl<$($t: Element),+> Seq for ($($t,)+) {
+                fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+                    let _ = (|| {
+                        $(visitor.element(self.$i.to_value_bag())?;)+
+                        ControlFlow::Continue(())
+                    })();
+                }
+
+                fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+                    let _ = (|| {
+                        $(visitor.borrowed_element(self.$i.to_value_bag())?;)+
+                        ControlFlow::Continue(())
+                    })();
+                }
+            }
+
+            impl<'v, $($t: Element + 'v),+> From<&'v ($($t,)+)> for ValueBag<'v> {
+                fn from(v: &'v ($($t,)+)) -> Self {
+                    ValueBag::from_dyn_seq(v)
+                }
+            }
+        )*
+    }
+);
+
+convert_tuple![
+    (0 A),
+    (0 A, 1 B),
+    (0 A, 1 B, 2 C),
+    (0 A, 1 B, 2 C, 3 D),
+    (0 A, 1 B, 2 C, 3 D, 4 E),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L),
+];
+
+/// A sequence with a function applied to each of its elements.
+///
+/// This type is returned by [`ValueBag::map_seq`].
+pub struct MapSeq<'v, F> {
+    value: ValueBag<'v>,
+    f: F,
+}
+
+impl<'v, F> MapSeq<'v, F>
+where
+    F: Fn(ValueBag) -> ValueBag,
+{
+    /// Get a regular [`ValueBag`] from this type.
+    pub fn by_ref(&self) -> ValueBag<'_> {
+        ValueBag {
+            inner: Internal::AnonSeq(self),
+        }
+    }
+}
+
+impl<'v, F> Seq for MapSeq<'v, F>
+where
+    F: Fn(ValueBag) -> ValueBag,
+{
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        struct Map<'a, 'b, F> {
+            visitor: &'a mut dyn Visitor<'b>,
+            f: &'a F,
+            done: bool,
+        }
+
+        impl<'a, 'b, 'v, F: Fn(ValueBag) -> ValueBag> ExtendValue<'v> for Map<'a, 'b, F> {
+            fn extend(&mut self, inner: Internal<'_>) {
+                if !self.done {
+                    self.done = self
+                        .visitor
+                        .element((self.f)(ValueBag { inner }))
+                        .is_break();
+                }
+            }
+        }
+
+        let _ = self.value.inner.extend_from(Map {
+            visitor,
+            f: &self.f,
+            done: false,
+        });
+    }
+}
+
+#[repr(transparent)]
+struct BoolBits<const N: usize>([bool; N]);
+
+impl<const N: usize> BoolBits<N> {
+    const fn new_ref(v: &[bool; N]) -> &BoolBits<N> {
+        // SAFETY: `BoolBits<N>` and `[bool; N]` have the same ABI
+        unsafe { &*(v as *const [bool; N] as *const BoolBits<N>) }
+    }
+}
+
+impl<const N: usize> Seq for BoolBits<N> {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        for v in self.0 {
+            if let ControlFlow::Break(()) = visitor.element(v.into()) {
+                return;
+            }
+        }
+    }
+
+    fn fmt_display(&self, f: &mut fmt::Formatter) -> Option<fmt::Result> {
+        Some(
+            self.0
+                .iter()
+                .try_for_each(|v| fmt::Write::write_char(f, if *v { '1' } else { '0' })),
+        )
+    }
+}
+
+#[derive(Default)]
 pub(crate) struct ExtendPrimitive<S, T>(S, PhantomData<T>);
 
 impl<S, T> ExtendPrimitive<S, T> {
@@ -410,9 +1008,16 @@ impl<'v, S: ExtendValue<'v>> Visitor<'v> for ExtendVisitor<S> {
 impl<'v> Internal<'v> {
     #[inline]
     pub(crate) fn extend<S: Default + ExtendValue<'v>>(&self) -> Option<S> {
-        struct SeqVisitor<S>(Option<S>);
+        self.extend_from(S::default())
+    }
+
+    pub(crate) fn extend_from<S: ExtendValue<'v>>(&self, seq: S) -> Option<S> {
+        struct SeqVisitor<S> {
+            seed: Option<S>,
+            seq: Option<S>,
+        }
 
-        impl<'v, S: Default + ExtendValue<'v>> InternalVisitor<'v> for SeqVisitor<S> {
+        impl<'v, S: ExtendValue<'v>> InternalVisitor<'v> for SeqVisitor<S> {
             #[inline]
             fn fill(&mut self, v: &dyn crate::fill::Fill) -> Result<(), Error> {
                 v.fill(Slot::new(self))
@@ -468,6 +1073,11 @@ impl<'v> Internal<'v> {
                 Ok(())
             }
 
+            #[inline]
+            fn bytes(&mut self, _: &[u8]) -> Result<(), Error> {
+                Ok(())
+            }
+
             #[inline]
             fn none(&mut self) -> Result<(), Error> {
                 Ok(())
@@ -482,7 +1092,9 @@ impl<'v> Internal<'v> {
             #[cfg(feature = "sval2")]
             #[inline]
             fn sval2(&mut self, v: &dyn crate::internal::sval::v2::Value) -> Result<(), Error> {
-                self.0 = crate::internal::sval::v2::seq::extend(v);
+                if let Some(seed) = self.seed.take() {
+                    self.seq = crate::internal::sval::v2::seq::extend(seed, v);
+                };
 
                 Ok(())
             }
@@ -493,7 +1105,9 @@ impl<'v> Internal<'v> {
                 &mut self,
                 v: &'v dyn crate::internal::sval::v2::Value,
             ) -> Result<(), Error> {
-                self.0 = crate::internal::sval::v2::seq::extend_borrowed(v);
+                if let Some(seed) = self.seed.take() {
+                    self.seq = crate::internal::sval::v2::seq::extend_borrowed(seed, v);
+                };
 
                 Ok(())
             }
@@ -504,23 +1118,35 @@ impl<'v> Internal<'v> {
                 &mut self,
                 v: &dyn crate::internal::serde::v1::Serialize,
             ) -> Result<(), Error> {
-                self.0 = crate::internal::serde::v1::seq::extend(v);
+                if let Some(seed) = self.seed.take() {
+                    self.seq = crate::internal::serde::v1::seq::extend(seed, v);
+                };
+
+                Ok(())
+            }
 
+            #[cfg(feature = "map")]
+            #[inline]
+            fn map(&mut self, _: &dyn crate::internal::map::Map) -> Result<(), Error> {
                 Ok(())
             }
 
             fn seq(&mut self, seq: &dyn Seq) -> Result<(), Error> {
-                let mut s = ExtendVisitor(S::default());
-                seq.visit(&mut s);
-                self.0 = Some(s.0);
+                if let Some(seed) = self.seed.take() {
+                    let mut s = ExtendVisitor(seed);
+                    seq.visit(&mut s);
+                    self.seq = Some(s.0);
+                }
 
                 Ok(())
             }
 
             fn borrowed_seq(&mut self, seq: &'v dyn Seq) -> Result<(), Error> {
-                let mut s = ExtendVisitor(S::default());
-                seq.borrowed_visit(&mut s);
-                self.0 = Some(s.0);
+                if let Some(seed) = self.seed.take() {
+                    let mut s = ExtendVisitor(seed);
+                    seq.borrowed_visit(&mut s);
+                    self.seq = Some(s.0);
+                }
 
                 Ok(())
             }
@@ -530,10 +1156,13 @@ impl<'v> Internal<'v> {
             }
         }
 
-        let mut visitor = SeqVisitor(None);
+        let mut visitor = SeqVisitor {
+            seed: Some(seq),
+            seq: None,
+        };
         let _ = self.internal_visit(&mut visitor);
 
-        visitor.0
+        visitor.seq
     }
 }
 
@@ -541,7 +1170,74 @@ impl<'v> Internal<'v> {
 mod alloc_support {
     use super::*;
 
-    use crate::std::borrow::Cow;
+    use crate::std::{
+        borrow::Cow,
+        collections::{BTreeSet, VecDeque},
+    };
+
+    impl<T: Element> Seq for Vec<T> {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            self.as_slice().visit(visitor)
+        }
+
+        fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+            self.as_slice().borrowed_visit(visitor)
+        }
+    }
+
+    impl<T: Element> Seq for VecDeque<T> {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            for v in self {
+                if let ControlFlow::Break(()) = visitor.element(v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+
+        fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+            for v in self {
+                if let ControlFlow::Break(()) = visitor.borrowed_element(v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+    }
+
+    impl<'v, T> From<&'v VecDeque<T>> for ValueBag<'v>
+    where
+        T: Element + 'v,
+    {
+        fn from(v: &'v VecDeque<T>) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<T: Element> Seq for BTreeSet<T> {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            for v in self {
+                if let ControlFlow::Break(()) = visitor.element(v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+
+        fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+            for v in self {
+                if let ControlFlow::Break(()) = visitor.borrowed_element(v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+    }
+
+    impl<'v, T> From<&'v BTreeSet<T>> for ValueBag<'v>
+    where
+        T: Element + 'v,
+    {
+        fn from(v: &'v BTreeSet<T>) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
 
     impl<'v> ValueBag<'v> {
         /// Try get a collection `S` of strings from this value.
@@ -607,6 +1303,176 @@ pub(crate) mod owned {
         v.visit(&mut buf);
         Ok(OwnedSeq(buf.0.into_boxed_slice()))
     }
+
+    pub(crate) fn concat(a: &Internal, b: &Internal) -> Option<OwnedSeq> {
+        #[derive(Default)]
+        struct Concat(Vec<OwnedValueBag>);
+
+        impl<'v> ExtendValue<'v> for Concat {
+            fn extend(&mut self, inner: Internal) {
+                self.0.push(ValueBag { inner }.to_owned());
+            }
+        }
+
+        let a = a.extend::<Concat>()?;
+        let b = b.extend_from(a)?;
+
+        Some(OwnedSeq(b.0.into_boxed_slice()))
+    }
+
+    pub(crate) fn collect(iter: impl IntoIterator<Item = OwnedValueBag>) -> OwnedSeq {
+        OwnedSeq(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_support {
+    use super::*;
+
+    use crate::{
+        fill::Fill,
+        std::{
+            collections::HashSet,
+            ffi::{OsStr, OsString},
+            path::{Path, PathBuf},
+        },
+    };
+
+    impl<T: Element, H> Seq for HashSet<T, H> {
+        fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+            for v in self {
+                if let ControlFlow::Break(()) = visitor.element(v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+
+        fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+            for v in self {
+                if let ControlFlow::Break(()) = visitor.borrowed_element(v.to_value_bag()) {
+                    return;
+                }
+            }
+        }
+    }
+
+    impl<'v, T, H> From<&'v HashSet<T, H>> for ValueBag<'v>
+    where
+        T: Element + 'v,
+    {
+        fn from(v: &'v HashSet<T, H>) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, 'a, const N: usize> From<&'v [&'a Path; N]> for ValueBag<'v> {
+        fn from(v: &'v [&'a Path; N]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, 'a, 'b> From<&'v &'a [&'b Path]> for ValueBag<'v> {
+        fn from(v: &'v &'a [&'b Path]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, const N: usize> From<&'v [PathBuf; N]> for ValueBag<'v> {
+        fn from(v: &'v [PathBuf; N]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, 'a> From<&'v &'a [PathBuf]> for ValueBag<'v> {
+        fn from(v: &'v &'a [PathBuf]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v> From<&'v Vec<PathBuf>> for ValueBag<'v> {
+        fn from(v: &'v Vec<PathBuf>) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, 'a, const N: usize> From<&'v [&'a OsStr; N]> for ValueBag<'v> {
+        fn from(v: &'v [&'a OsStr; N]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, 'a, 'b> From<&'v &'a [&'b OsStr]> for ValueBag<'v> {
+        fn from(v: &'v &'a [&'b OsStr]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, const N: usize> From<&'v [OsString; N]> for ValueBag<'v> {
+        fn from(v: &'v [OsString; N]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v, 'a> From<&'v &'a [OsString]> for ValueBag<'v> {
+        fn from(v: &'v &'a [OsString]) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    impl<'v> From<&'v Vec<OsString>> for ValueBag<'v> {
+        fn from(v: &'v Vec<OsString>) -> Self {
+            ValueBag::from_dyn_seq(v)
+        }
+    }
+
+    // Paths and OS strings are captured as strings when they're valid UTF8,
+    // and are lossily converted into strings when they're not
+    fn os_str_to_value_bag(os_str: &impl AsRef<OsStr>) -> ValueBag<'_> {
+        match os_str.as_ref().to_str() {
+            Some(os_str) => ValueBag::from_str(os_str),
+            None => ValueBag::from_fill(OsStrLossy::new_ref(os_str)),
+        }
+    }
+
+    impl Element for &Path {
+        fn to_value_bag(&self) -> ValueBag<'_> {
+            os_str_to_value_bag(self)
+        }
+    }
+
+    impl Element for PathBuf {
+        fn to_value_bag(&self) -> ValueBag<'_> {
+            os_str_to_value_bag(self)
+        }
+    }
+
+    impl Element for &OsStr {
+        fn to_value_bag(&self) -> ValueBag<'_> {
+            os_str_to_value_bag(self)
+        }
+    }
+
+    impl Element for OsString {
+        fn to_value_bag(&self) -> ValueBag<'_> {
+            os_str_to_value_bag(self)
+        }
+    }
+
+    #[repr(transparent)]
+    struct OsStrLossy<S>(S);
+
+    impl<S> OsStrLossy<S> {
+        fn new_ref(v: &S) -> &OsStrLossy<S> {
+            // SAFETY: `OsStrLossy<S>` and `S` have the same ABI
+            unsafe { &*(v as *const S as *const OsStrLossy<S>) }
+        }
+    }
+
+    impl<S: AsRef<OsStr>> Fill for OsStrLossy<S> {
+        fn fill(&self, slot: Slot) -> Result<(), Error> {
+            slot.fill(|visitor| visitor.str(&self.0.as_ref().to_string_lossy()))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -689,6 +1555,269 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn parse_f64_seq() {
+        let value = ValueBag::from(&["1.5", "x", "2"]);
+
+        assert_eq!(
+            Some(vec![None, None, None]),
+            value.to_f64_seq::<Vec<Option<f64>>>()
+        );
+        assert_eq!(
+            Some(vec![Some(1.5), None, Some(2.0)]),
+            value.parse_f64_seq::<Vec<Option<f64>>>()
+        );
+
+        assert_eq!(
+            Some(vec![Some(1.0), Some(2.5)]),
+            ValueBag::from(&[1.0, 2.5]).parse_f64_seq::<Vec<Option<f64>>>()
+        );
+
+        assert_eq!(
+            None,
+            ValueBag::from("1.5").parse_f64_seq::<Vec<Option<f64>>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn as_f64_seq_f32() {
+        let floats = [0.1f32, 1.25, -3.0];
+
+        assert_eq!(
+            vec![0.1f32 as f64, 1.25, -3.0],
+            ValueBag::from(&floats).as_f64_seq::<Vec<f64>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn as_f32_seq() {
+        assert_eq!(
+            Vec::<f32>::new(),
+            ValueBag::from(1.0f32).as_f32_seq::<Vec<f32>>()
+        );
+
+        assert_eq!(
+            vec![0.1f32, 1.25, -3.0],
+            ValueBag::from(&[0.1f64, 1.25, -3.0]).as_f32_seq::<Vec<f32>>()
+        );
+
+        assert_eq!(
+            vec![0.1f32, 1.25, -3.0],
+            ValueBag::from(&[0.1f32, 1.25, -3.0]).as_f32_seq::<Vec<f32>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn count_seq() {
+        let bools = [true; 1000];
+
+        assert_eq!(Some(1000), ValueBag::from(&bools).count_seq());
+        assert_eq!(
+            1000,
+            ValueBag::from(&bools)
+                .to_bool_seq::<Vec<Option<bool>>>()
+                .expect("invalid value")
+                .len()
+        );
+
+        assert_eq!(None, ValueBag::from(true).count_seq());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn non_zero_seq() {
+        let values = [
+            NonZeroU64::new(1).unwrap(),
+            NonZeroU64::new(2).unwrap(),
+            NonZeroU64::new(3).unwrap(),
+        ];
+        let slice = &values[..];
+
+        assert_eq!(
+            Some(vec![Some(1), Some(2), Some(3)]),
+            ValueBag::from(&values).to_u64_seq::<Vec<Option<u64>>>()
+        );
+        assert_eq!(
+            Some(vec![Some(1), Some(2), Some(3)]),
+            ValueBag::from(&slice).to_u64_seq::<Vec<Option<u64>>>()
+        );
+    }
+
+    #[test]
+    #[cfg(value_bag_saturating)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn saturating_seq() {
+        let values = [Saturating(-1i64), Saturating(0), Saturating(i64::MAX)];
+        let slice = &values[..];
+
+        assert_eq!(
+            Some(vec![Some(-1), Some(0), Some(i64::MAX)]),
+            ValueBag::from(&values).to_i64_seq::<Vec<Option<i64>>>()
+        );
+        assert_eq!(
+            Some(vec![Some(-1), Some(0), Some(i64::MAX)]),
+            ValueBag::from(&slice).to_i64_seq::<Vec<Option<i64>>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn wrapping_seq() {
+        let values = [Wrapping(1u64), Wrapping(2), Wrapping(3)];
+        let slice = &values[..];
+
+        assert_eq!(
+            Some(vec![Some(1), Some(2), Some(3)]),
+            ValueBag::from(&values).to_u64_seq::<Vec<Option<u64>>>()
+        );
+        assert_eq!(
+            Some(vec![Some(1), Some(2), Some(3)]),
+            ValueBag::from(&slice).to_u64_seq::<Vec<Option<u64>>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fold() {
+        let value = ValueBag::from_seq_slice(&[1u64, 2, 3]);
+        assert_eq!(6, value.fold(0, |sum, v| sum + v.to_u64().unwrap()));
+
+        let value = ValueBag::from_seq_slice(&[0u64; 0]);
+        assert_eq!(0, value.fold(0, |sum, v| sum + v.to_u64().unwrap()));
+
+        let value = ValueBag::from(4u64);
+        assert_eq!(4, value.fold(0, |sum, v| sum + v.to_u64().unwrap()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn first_some() {
+        let value = ValueBag::from_opt_seq_slice(&[None, Some(2u64)]);
+        assert_eq!(Some(2), value.first_some().and_then(|v| v.to_u64()));
+
+        let value = ValueBag::from_opt_seq_slice(&[None::<u64>, None]);
+        assert!(value.first_some().is_none());
+
+        assert_eq!(
+            Some(1),
+            ValueBag::from(1).first_some().and_then(|v| v.to_u64())
+        );
+        assert!(ValueBag::empty().first_some().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn value_bag_seq() {
+        let elements = [
+            ValueBag::from(1u64),
+            ValueBag::from("a string"),
+            ValueBag::from(true),
+            ValueBag::from_seq_slice(&[2u64, 3]),
+        ];
+        let slice = &elements[..];
+
+        for value in [ValueBag::from(&elements), ValueBag::from(&slice)] {
+            assert_eq!(Some(4), value.count_seq());
+
+            assert_eq!(Some(1), value.seq_get(0).and_then(|v| v.to_u64()));
+            assert_eq!(
+                Some("a string"),
+                value.seq_get(1).and_then(|v| v.to_borrowed_str())
+            );
+            assert_eq!(Some(true), value.seq_get(2).and_then(|v| v.to_bool()));
+            assert_eq!(
+                Some(vec![Some(2), Some(3)]),
+                value
+                    .seq_get(3)
+                    .and_then(|v| v.to_u64_seq::<Vec<Option<u64>>>())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn seq_get() {
+        let value = ValueBag::from_seq_slice(&[1u64, 2, 3]);
+
+        assert_eq!(Some(2), value.seq_get(1).and_then(|v| v.to_u64()));
+        assert!(value.seq_get(3).is_none());
+
+        assert!(ValueBag::from(1).seq_get(0).is_none());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_display_slice() {
+        struct Id(u32);
+
+        impl fmt::Display for Id {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "#{}", self.0)
+            }
+        }
+
+        let value = ValueBag::from_display_slice(&[&Id(1) as &dyn fmt::Display, &"a", &true]);
+
+        assert_eq!(Some(3), value.count_seq());
+        assert_eq!("[#1, a, true]", format!("{}", value));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn map_seq() {
+        let value = ValueBag::from(&[1u64, 2, 3]).map_seq(|v| ValueBag::from(v.as_f64() * 2.0));
+
+        assert_eq!(
+            Some(vec![Some(2.0), Some(4.0), Some(6.0)]),
+            value.by_ref().to_f64_seq::<Vec<Option<f64>>>()
+        );
+        assert_eq!("[2.0, 4.0, 6.0]", format!("{}", value.by_ref()));
+
+        let value = ValueBag::from(1u64).map_seq(|v| v);
+        assert_eq!(Some(0), value.by_ref().count_seq());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_bool_array_as_bits() {
+        let value = ValueBag::from_bool_array_as_bits(&[true, false, true]);
+
+        assert_eq!("101", format!("{}", value));
+        assert_eq!("[true, false, true]", format!("{:?}", value));
+        assert_eq!(
+            Some(vec![Some(true), Some(false), Some(true)]),
+            value.to_bool_seq::<Vec<Option<bool>>>()
+        );
+
+        assert_eq!("", format!("{}", ValueBag::from_bool_array_as_bits(&[])));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn tuple_seq() {
+        let tuple = ("a", 1u64, true);
+        let value = ValueBag::from(&tuple);
+
+        assert_eq!(Some(3), value.count_seq());
+
+        assert_eq!(
+            Some(vec![Some("a"), None, None]),
+            value.to_borrowed_str_seq::<Vec<Option<&str>>>()
+        );
+        assert_eq!(
+            Some(vec![None, Some(1u64), None]),
+            value.to_u64_seq::<Vec<Option<u64>>>()
+        );
+        assert_eq!(
+            Some(vec![None, None, Some(true)]),
+            value.to_bool_seq::<Vec<Option<bool>>>()
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn to_u128_seq() {
@@ -744,11 +1873,90 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn opt_str_seq() {
+        let v = [Some("a"), None, Some("c")];
+
+        assert_eq!(
+            Some(vec![Some("a"), None, Some("c")]),
+            ValueBag::from(&v).to_borrowed_str_seq::<Vec<Option<&str>>>()
+        );
+
+        let v: &[Option<&str>] = &[None, Some("b")];
+
+        assert_eq!(
+            Some(vec![None, Some("b")]),
+            ValueBag::from(&v).to_borrowed_str_seq::<Vec<Option<&str>>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn opt_seq_slice() {
+        let v = [Some("a"), None, Some("c")];
+        let v = ValueBag::from_opt_seq_slice(&v);
+
+        assert_eq!(
+            Some(vec![Some("a"), None, Some("c")]),
+            v.to_borrowed_str_seq::<Vec<Option<&str>>>()
+        );
+
+        assert_eq!(
+            Some(vec![None, Some(2u64), Some(3u64)]),
+            ValueBag::from_opt_seq_slice(&[None, Some(2u8), Some(3u8)])
+                .to_u64_seq::<Vec<Option<u64>>>()
+        );
+    }
+
     #[cfg(feature = "alloc")]
     mod alloc_support {
         use super::*;
 
-        use crate::std::borrow::Cow;
+        use crate::std::{
+            borrow::Cow,
+            collections::{BTreeSet, VecDeque},
+        };
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn non_zero_vec() {
+            let values = vec![
+                NonZeroU64::new(1).unwrap(),
+                NonZeroU64::new(2).unwrap(),
+                NonZeroU64::new(3).unwrap(),
+            ];
+
+            assert_eq!(
+                Some(vec![Some(1), Some(2), Some(3)]),
+                ValueBag::from(&values).to_u64_seq::<Vec<Option<u64>>>()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn vec_deque() {
+            let mut v = VecDeque::new();
+            v.push_back(2u64);
+            v.push_back(3);
+            v.push_front(1);
+
+            assert_eq!(
+                Some(vec![Some(1), Some(2), Some(3)]),
+                ValueBag::from(&v).to_u64_seq::<Vec<Option<u64>>>()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn btree_set() {
+            let v = BTreeSet::from([3u64, 1, 2]);
+
+            assert_eq!(
+                Some(vec![Some(1), Some(2), Some(3)]),
+                ValueBag::from(&v).to_u64_seq::<Vec<Option<u64>>>()
+            );
+        }
 
         #[test]
         #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
@@ -777,4 +1985,89 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "std")]
+    mod std_support {
+        use super::*;
+
+        use crate::std::{
+            borrow::Cow,
+            collections::HashSet,
+            ffi::{OsStr, OsString},
+            path::{Path, PathBuf},
+        };
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn path_seq() {
+            let expected = Some(vec![
+                Some(Cow::Borrowed("a/b")),
+                Some(Cow::Borrowed("c.txt")),
+            ]);
+
+            let v = [Path::new("a/b"), Path::new("c.txt")];
+            assert_eq!(
+                expected,
+                ValueBag::from(&v).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+            assert_eq!(
+                expected,
+                ValueBag::from(&&v[..]).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+
+            let v = vec![PathBuf::from("a/b"), PathBuf::from("c.txt")];
+            assert_eq!(
+                expected,
+                ValueBag::from(&v).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+            assert_eq!(
+                expected,
+                ValueBag::from(&&v[..]).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn os_str_seq() {
+            let expected = Some(vec![Some(Cow::Borrowed("a")), Some(Cow::Borrowed("b"))]);
+
+            let v = [OsStr::new("a"), OsStr::new("b")];
+            assert_eq!(
+                expected,
+                ValueBag::from(&v).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+            assert_eq!(
+                expected,
+                ValueBag::from(&&v[..]).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+
+            let v = vec![OsString::from("a"), OsString::from("b")];
+            assert_eq!(
+                expected,
+                ValueBag::from(&v).to_str_seq::<Vec<Option<Cow<str>>>>()
+            );
+
+            #[cfg(unix)]
+            {
+                use crate::std::os::unix::ffi::OsStrExt;
+
+                let v = [OsStr::from_bytes(b"a\xffb")];
+                assert_eq!(
+                    Some(vec![Some(Cow::Owned("a\u{fffd}b".into()))]),
+                    ValueBag::from(&v).to_str_seq::<Vec<Option<Cow<str>>>>()
+                );
+            }
+        }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn hash_set() {
+            let v = HashSet::from([3u64, 1, 2]);
+
+            let mut seq = ValueBag::from(&v).to_u64_seq::<Vec<Option<u64>>>().unwrap();
+            seq.sort();
+
+            assert_eq!(vec![Some(1), Some(2), Some(3)], seq);
+        }
+    }
 }
@@ -0,0 +1,151 @@
+//! Integration between `Value` and `serde_json`.
+//!
+//! JSON objects are captured as maps, so their entries can be inspected
+//! without needing to serialize them.
+
+use value_bag_serde1::json::{Map as JsonMap, Value as JsonValue};
+
+use crate::{
+    internal::{
+        map::{Map, Visitor},
+        Internal,
+    },
+    std::{ops::ControlFlow, string::String},
+    ValueBag,
+};
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a JSON object.
+    ///
+    /// The object will be captured as a map. Its keys are captured as strings,
+    /// and its values as primitives where possible. Nested objects are also captured
+    /// as maps, and arrays are captured using their `serde::Serialize` implementation.
+    pub const fn from_json_map(value: &'v JsonMap<String, JsonValue>) -> Self {
+        ValueBag {
+            inner: Internal::AnonMap(JsonObject::new_ref(value)),
+        }
+    }
+}
+
+#[repr(transparent)]
+struct JsonObject(JsonMap<String, JsonValue>);
+
+impl JsonObject {
+    const fn new_ref(v: &JsonMap<String, JsonValue>) -> &JsonObject {
+        // SAFETY: `JsonObject` and `JsonMap<String, JsonValue>` have the same ABI
+        unsafe { &*(v as *const JsonMap<String, JsonValue> as *const JsonObject) }
+    }
+}
+
+impl Map for JsonObject {
+    fn visit(&self, visitor: &mut dyn Visitor<'_>) {
+        for (k, v) in self.0.iter() {
+            if let ControlFlow::Break(()) = visitor.entry(ValueBag::from(k), from_json_value(v)) {
+                return;
+            }
+        }
+    }
+
+    fn borrowed_visit<'v>(&'v self, visitor: &mut dyn Visitor<'v>) {
+        for (k, v) in self.0.iter() {
+            if let ControlFlow::Break(()) =
+                visitor.borrowed_entry(ValueBag::from(k), from_json_value(v))
+            {
+                return;
+            }
+        }
+    }
+}
+
+fn from_json_value(value: &JsonValue) -> ValueBag<'_> {
+    match value {
+        JsonValue::Null => ValueBag::empty(),
+        JsonValue::Bool(v) => ValueBag::from(*v),
+        JsonValue::Number(v) => {
+            if let Some(v) = v.as_u64() {
+                ValueBag::from(v)
+            } else if let Some(v) = v.as_i64() {
+                ValueBag::from(v)
+            } else if let Some(v) = v.as_f64() {
+                ValueBag::from(v)
+            } else {
+                ValueBag::from_serde1(v)
+            }
+        }
+        JsonValue::String(v) => ValueBag::from(v),
+        JsonValue::Array(v) => ValueBag::from_serde1(v),
+        JsonValue::Object(v) => ValueBag::from_json_map(v),
+    }
+}
+
+impl<'v> From<&'v JsonMap<String, JsonValue>> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v JsonMap<String, JsonValue>) -> Self {
+        ValueBag::from_json_map(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    use crate::std::vec::Vec;
+
+    use value_bag_serde1::json::json;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn json_map_to_map() {
+        let json = json!({
+            "a": 1,
+            "b": -2,
+            "c": null,
+        });
+        let map = json.as_object().unwrap();
+
+        assert_eq!(
+            Some(vec![
+                (Some(String::from("a")), Some(1)),
+                (Some(String::from("b")), Some(-2)),
+                (Some(String::from("c")), None),
+            ]),
+            ValueBag::from_json_map(map).to_map::<String, i64, Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn json_map_str() {
+        let json = json!({
+            "a": "a string",
+        });
+        let map = json.as_object().unwrap();
+
+        assert_eq!(
+            Some(vec![(
+                Some(String::from("a")),
+                Some(String::from("a string"))
+            )]),
+            ValueBag::from(map).to_map::<String, String, Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn json_map_fmt() {
+        let json = json!({
+            "a": 1,
+            "b": [true, false],
+            "c": { "d": "a string" },
+        });
+        let map = json.as_object().unwrap();
+
+        assert_eq!(
+            "{\"a\": 1, \"b\": [true, false], \"c\": {\"d\": \"a string\"}}",
+            format!("{:?}", ValueBag::from_json_map(map))
+        );
+    }
+}
@@ -1 +1,3 @@
+#[cfg(feature = "serde1_json")]
+pub(crate) mod json;
 pub(crate) mod v1;
@@ -36,7 +36,21 @@ impl<'v> ValueBag<'v> {
         }
     }
 
-    // NOTE: no `from_dyn_serde1` until `erased-serde` stabilizes
+    /// Get a value from an erased structured type without capturing support.
+    ///
+    /// The erased `Serialize` trait comes from `erased-serde`, which isn't stable yet,
+    /// so this method is only available when building with
+    /// `RUSTFLAGS="--cfg value_bag_unstable_serde1_dyn"`.
+    #[inline]
+    #[cfg(value_bag_unstable_serde1_dyn)]
+    pub const fn from_dyn_serde1(value: &'v dyn Serialize) -> Self {
+        ValueBag {
+            inner: Internal::AnonSerde1(value),
+        }
+    }
+
+    // NOTE: no public `from_dyn_serde1` until `erased-serde` stabilizes
+    #[cfg(not(value_bag_unstable_serde1_dyn))]
     pub(crate) const fn from_dyn_serde1(value: &'v dyn Serialize) -> Self {
         ValueBag {
             inner: Internal::AnonSerde1(value),
@@ -180,6 +194,11 @@ impl<'v> value_bag_serde1::lib::Serialize for ValueBag<'v> {
                 self.result()
             }
 
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                self.result = Some(self.serializer()?.serialize_bytes(v));
+                self.result()
+            }
+
             fn none(&mut self) -> Result<(), Error> {
                 self.result = Some(self.serializer()?.serialize_none());
                 self.result()
@@ -208,6 +227,12 @@ impl<'v> value_bag_serde1::lib::Serialize for ValueBag<'v> {
                 self.result()
             }
 
+            #[cfg(feature = "map")]
+            fn map(&mut self, v: &dyn crate::internal::map::Map) -> Result<(), Error> {
+                self.result = Some(serialize_map(self.serializer()?, v));
+                self.result()
+            }
+
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
                 self.result = Some(Err(S::Error::custom(msg)));
                 self.result()
@@ -279,6 +304,44 @@ fn serialize_seq<S: value_bag_serde1::lib::Serializer>(
     s.serializer.end()
 }
 
+#[cfg(feature = "map")]
+fn serialize_map<S: value_bag_serde1::lib::Serializer>(
+    s: S,
+    map: &dyn crate::internal::map::Map,
+) -> Result<S::Ok, S::Error> {
+    use crate::std::ops::ControlFlow;
+
+    use value_bag_serde1::lib::ser::SerializeMap;
+
+    struct SerializeVisitor<S: SerializeMap> {
+        serializer: S,
+        err: Option<S::Error>,
+    }
+
+    impl<'v, S: SerializeMap> crate::internal::map::Visitor<'v> for SerializeVisitor<S> {
+        fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()> {
+            match self.serializer.serialize_entry(&k, &v) {
+                Ok(()) => ControlFlow::Continue(()),
+                Err(e) => {
+                    self.err = Some(e);
+                    ControlFlow::Break(())
+                }
+            }
+        }
+    }
+
+    let mut s = SerializeVisitor {
+        serializer: s.serialize_map(None)?,
+        err: None,
+    };
+    map.visit(&mut s);
+    if let Some(e) = s.err {
+        return Err(e);
+    }
+
+    s.serializer.end()
+}
+
 pub(crate) fn internal_visit(v: &dyn Serialize, visitor: &mut dyn InternalVisitor<'_>) -> bool {
     struct VisitorSerializer<'a, 'v>(&'a mut dyn InternalVisitor<'v>);
 
@@ -365,8 +428,8 @@ pub(crate) fn internal_visit(v: &dyn Serialize, visitor: &mut dyn InternalVisito
             self.0.none().map_err(|_| Unsupported)
         }
 
-        fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
-            Err(Unsupported)
+        fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+            self.0.bytes(v).map_err(|_| Unsupported)
         }
 
         fn serialize_str(self, s: &str) -> Result<Self::Ok, Self::Error> {
@@ -501,14 +564,12 @@ pub(crate) mod seq {
     use crate::internal::seq::ExtendValue;
 
     #[inline]
-    pub(crate) fn extend<'a, S: Default + ExtendValue<'a>>(v: &dyn Serialize) -> Option<S> {
-        use crate::std::marker::PhantomData;
-
-        struct Root<S>(PhantomData<S>);
+    pub(crate) fn extend<'a, S: ExtendValue<'a>>(seq: S, v: &dyn Serialize) -> Option<S> {
+        struct Root<S>(S);
 
         struct Seq<S>(S);
 
-        impl<'a, S: Default + ExtendValue<'a>> value_bag_serde1::lib::Serializer for Root<S> {
+        impl<'a, S: ExtendValue<'a>> value_bag_serde1::lib::Serializer for Root<S> {
             type Ok = S;
 
             type Error = Unsupported;
@@ -517,7 +578,7 @@ pub(crate) mod seq {
 
             type SerializeTuple = Seq<S>;
 
-            type SerializeTupleStruct = value_bag_serde1::lib::ser::Impossible<S, Unsupported>;
+            type SerializeTupleStruct = Seq<S>;
 
             type SerializeTupleVariant = value_bag_serde1::lib::ser::Impossible<S, Unsupported>;
 
@@ -630,7 +691,7 @@ pub(crate) mod seq {
             }
 
             fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-                Ok(Seq(S::default()))
+                Ok(Seq(self.0))
             }
 
             fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
@@ -640,9 +701,9 @@ pub(crate) mod seq {
             fn serialize_tuple_struct(
                 self,
                 _: &'static str,
-                _: usize,
+                len: usize,
             ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-                Err(Unsupported)
+                self.serialize_seq(Some(len))
             }
 
             fn serialize_tuple_variant(
@@ -713,7 +774,24 @@ pub(crate) mod seq {
             }
         }
 
-        value_bag_serde1::lib::Serialize::serialize(v, Root::<S>(Default::default())).ok()
+        impl<'a, S: ExtendValue<'a>> value_bag_serde1::lib::ser::SerializeTupleStruct for Seq<S> {
+            type Ok = S;
+
+            type Error = Unsupported;
+
+            fn serialize_field<T: value_bag_serde1::lib::Serialize + ?Sized>(
+                &mut self,
+                value: &T,
+            ) -> Result<(), Self::Error> {
+                value_bag_serde1::lib::ser::SerializeSeq::serialize_element(self, value)
+            }
+
+            fn end(self) -> Result<Self::Ok, Self::Error> {
+                value_bag_serde1::lib::ser::SerializeSeq::end(self)
+            }
+        }
+
+        value_bag_serde1::lib::Serialize::serialize(v, Root(seq)).ok()
     }
 }
 
@@ -739,6 +817,33 @@ pub(crate) mod owned {
     }
 }
 
+#[cfg(value_bag_unstable_serde1_dyn)]
+impl<'v> From<&'v dyn Serialize> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v dyn Serialize) -> Self {
+        ValueBag::from_dyn_serde1(v)
+    }
+}
+
+#[cfg(value_bag_unstable_serde1_dyn)]
+impl<'v> From<Option<&'v dyn Serialize>> for ValueBag<'v> {
+    #[inline]
+    fn from(v: Option<&'v dyn Serialize>) -> Self {
+        ValueBag::from_option(v)
+    }
+}
+
+#[cfg(value_bag_unstable_serde1_dyn)]
+impl<'v, 'u> From<&'v &'u dyn Serialize> for ValueBag<'v>
+where
+    'u: 'v,
+{
+    #[inline]
+    fn from(v: &'v &'u dyn Serialize) -> Self {
+        ValueBag::from_dyn_serde1(*v)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_arch = "wasm32")]
@@ -835,6 +940,19 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn serde1_downcast_primitive() {
+        assert_eq!(
+            Some(&true),
+            ValueBag::capture_serde1(&true).downcast_ref::<bool>()
+        );
+        assert_eq!(
+            Some(&'a'),
+            ValueBag::capture_serde1(&'a').downcast_ref::<char>()
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn serde1_structured() {
@@ -843,6 +961,30 @@ mod tests {
         assert_ser_tokens(&ValueBag::from(42u64), &[Token::U64(42)]);
     }
 
+    #[test]
+    #[cfg(value_bag_unstable_serde1_dyn)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn serde1_dyn_structured() {
+        use value_bag_serde1::test::{assert_ser_tokens, Token};
+
+        let value: &dyn Serialize = &42u64;
+
+        assert_ser_tokens(&ValueBag::from_dyn_serde1(value), &[Token::U64(42)]);
+        assert_ser_tokens(&ValueBag::from(value), &[Token::U64(42)]);
+        assert_eq!(Some(42), ValueBag::from(value).to_u64());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn serde1_bytes() {
+        use value_bag_serde1::test::{assert_ser_tokens, Token};
+
+        assert_ser_tokens(
+            &ValueBag::from_bytes(&[1, 2, 3]),
+            &[Token::Bytes(&[1, 2, 3])],
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn serde1_debug() {
@@ -956,6 +1098,40 @@ mod tests {
                 ValueBag::capture_serde1(&[1.0, 2.0, 3.0,]).as_f64_seq::<Vec<f64>>()
             );
         }
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn serde1_tuple_to_seq() {
+            struct TupleStruct(u64, u64);
+
+            impl value_bag_serde1::lib::Serialize for TupleStruct {
+                fn serialize<S: value_bag_serde1::lib::Serializer>(
+                    &self,
+                    s: S,
+                ) -> Result<S::Ok, S::Error> {
+                    use value_bag_serde1::lib::ser::SerializeTupleStruct;
+
+                    let mut tuple = s.serialize_tuple_struct("TupleStruct", 2)?;
+                    tuple.serialize_field(&self.0)?;
+                    tuple.serialize_field(&self.1)?;
+                    tuple.end()
+                }
+            }
+
+            assert_eq!(
+                vec![Some(1), Some(2)],
+                ValueBag::capture_serde1(&(1u64, 2u64))
+                    .to_u64_seq::<Vec<Option<u64>>>()
+                    .expect("invalid value")
+            );
+
+            assert_eq!(
+                vec![Some(1), Some(2)],
+                ValueBag::capture_serde1(&TupleStruct(1, 2))
+                    .to_u64_seq::<Vec<Option<u64>>>()
+                    .expect("invalid value")
+            );
+        }
     }
 
     #[cfg(feature = "std")]
@@ -1006,4 +1182,27 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "map")]
+    mod map_support {
+        use super::*;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn serde1_stream_map() {
+            use value_bag_serde1::test::{assert_ser_tokens, Token};
+
+            assert_ser_tokens(
+                &ValueBag::from_entries(&[("a", 1), ("b", 2)]),
+                &[
+                    Token::Map { len: None },
+                    Token::Str("a"),
+                    Token::I64(1),
+                    Token::Str("b"),
+                    Token::I64(2),
+                    Token::MapEnd,
+                ],
+            );
+        }
+    }
 }
@@ -10,6 +10,8 @@ use crate::{
     Error, ValueBag,
 };
 
+use value_bag_sval2::fmt::TokenWrite as _;
+
 impl<'v> ValueBag<'v> {
     /// Get a value from a structured type.
     ///
@@ -142,6 +144,18 @@ impl<'sval> value_bag_sval2::lib_ref::ValueRef<'sval> for ValueBag<'sval> {
                 self.0.value(v).map_err(Error::from_sval2)
             }
 
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                self.0
+                    .value_computed(value_bag_sval2::lib::BinarySlice::new(v))
+                    .map_err(Error::from_sval2)
+            }
+
+            fn borrowed_bytes(&mut self, v: &'v [u8]) -> Result<(), Error> {
+                self.0
+                    .value(value_bag_sval2::lib::BinarySlice::new(v))
+                    .map_err(Error::from_sval2)
+            }
+
             fn none(&mut self) -> Result<(), Error> {
                 self.0.null().map_err(Error::from_sval2)
             }
@@ -199,6 +213,38 @@ impl<'sval> value_bag_sval2::lib_ref::ValueRef<'sval> for ValueBag<'sval> {
                 self.0.seq_end().map_err(Error::from_sval2)
             }
 
+            #[cfg(feature = "map")]
+            fn map(&mut self, v: &dyn crate::internal::map::Map) -> Result<(), Error> {
+                self.0.map_begin(None).map_err(Error::from_sval2)?;
+
+                let mut s = map::StreamVisitor {
+                    stream: &mut *self.0,
+                    err: None,
+                };
+                v.visit(&mut s);
+                if let Some(e) = s.err {
+                    return Err(Error::from_sval2(e));
+                }
+
+                self.0.map_end().map_err(Error::from_sval2)
+            }
+
+            #[cfg(feature = "map")]
+            fn borrowed_map(&mut self, v: &'v dyn crate::internal::map::Map) -> Result<(), Error> {
+                self.0.map_begin(None).map_err(Error::from_sval2)?;
+
+                let mut s = map::StreamVisitor {
+                    stream: &mut *self.0,
+                    err: None,
+                };
+                v.borrowed_visit(&mut s);
+                if let Some(e) = s.err {
+                    return Err(Error::from_sval2(e));
+                }
+
+                self.0.map_end().map_err(Error::from_sval2)
+            }
+
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
                 Err(Error::msg(msg))
             }
@@ -214,10 +260,118 @@ impl<'sval> value_bag_sval2::lib_ref::ValueRef<'sval> for ValueBag<'sval> {
 pub use value_bag_sval2::dynamic::Value;
 
 pub(in crate::internal) fn fmt(f: &mut fmt::Formatter, v: &dyn Value) -> Result<(), Error> {
-    value_bag_sval2::fmt::stream_to_fmt(f, v)?;
+    if f.alternate() {
+        value_bag_sval2::fmt::stream_to_token_write(Pretty { f, depth: 0 }, v)?;
+    } else {
+        value_bag_sval2::fmt::stream_to_fmt(f, v)?;
+    }
+
     Ok(())
 }
 
+/// A writer that indents nested structures like Rust's `{:#?}`.
+struct Pretty<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    depth: usize,
+}
+
+impl<'a, 'b> Pretty<'a, 'b> {
+    fn write_newline(&mut self) -> fmt::Result {
+        self.write_ws("\n")?;
+
+        for _ in 0..self.depth {
+            self.write_ws("    ")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_nested_begin(&mut self, punct: &str) -> fmt::Result {
+        self.depth += 1;
+        self.write_punct(punct)
+    }
+
+    fn write_nested_value_begin(&mut self, is_first: bool) -> fmt::Result {
+        if !is_first {
+            self.write_punct(",")?;
+        }
+
+        self.write_newline()
+    }
+
+    fn write_nested_end(&mut self, is_empty: bool, punct: &str) -> fmt::Result {
+        self.depth -= 1;
+
+        if !is_empty {
+            self.write_punct(",")?;
+            self.write_newline()?;
+        }
+
+        self.write_punct(punct)
+    }
+}
+
+impl<'a, 'b> fmt::Write for Pretty<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.f.write_str(s)
+    }
+}
+
+impl<'a, 'b> value_bag_sval2::fmt::TokenWrite for Pretty<'a, 'b> {
+    fn write_map_begin(&mut self) -> fmt::Result {
+        self.write_nested_begin("{")
+    }
+
+    fn write_map_key_begin(&mut self, is_first: bool) -> fmt::Result {
+        self.write_nested_value_begin(is_first)
+    }
+
+    fn write_map_end(&mut self, is_empty: bool) -> fmt::Result {
+        self.write_nested_end(is_empty, "}")
+    }
+
+    fn write_record_begin(&mut self) -> fmt::Result {
+        self.write_nested_begin("{")
+    }
+
+    fn write_record_value_begin(&mut self, field: &str, is_first: bool) -> fmt::Result {
+        self.write_nested_value_begin(is_first)?;
+
+        self.write_field(field)?;
+
+        self.write_punct(":")?;
+        self.write_ws(" ")
+    }
+
+    fn write_record_end(&mut self, is_empty: bool) -> fmt::Result {
+        self.write_nested_end(is_empty, "}")
+    }
+
+    fn write_seq_begin(&mut self) -> fmt::Result {
+        self.write_nested_begin("[")
+    }
+
+    fn write_seq_value_begin(&mut self, is_first: bool) -> fmt::Result {
+        self.write_nested_value_begin(is_first)
+    }
+
+    fn write_seq_end(&mut self, is_empty: bool) -> fmt::Result {
+        self.write_nested_end(is_empty, "]")
+    }
+
+    fn write_tuple_begin(&mut self) -> fmt::Result {
+        self.write_nested_begin("(")
+    }
+
+    fn write_tuple_value_begin(&mut self, is_first: bool) -> fmt::Result {
+        self.write_nested_value_begin(is_first)
+    }
+
+    fn write_tuple_end(&mut self, is_empty: bool) -> fmt::Result {
+        self.write_nested_end(is_empty, ")")
+    }
+}
+
 #[cfg(feature = "serde1")]
 pub(in crate::internal) fn serde1<S>(s: S, v: &dyn Value) -> Result<S::Ok, S::Error>
 where
@@ -281,6 +435,21 @@ impl<'a, 'v> value_bag_sval2::lib::Stream<'v> for VisitorStream<'a, 'v> {
         self.visitor.f64(v).map_err(Error::into_sval2)
     }
 
+    fn tagged_begin(
+        &mut self,
+        tag: Option<&value_bag_sval2::lib::Tag>,
+        _: Option<&value_bag_sval2::lib::Label>,
+        _: Option<&value_bag_sval2::lib::Index>,
+    ) -> value_bag_sval2::lib::Result {
+        if let Some(tag) = tag {
+            self.visitor
+                .sval2_tag(tag_name(tag))
+                .map_err(Error::into_sval2)?;
+        }
+
+        Ok(())
+    }
+
     fn text_begin(&mut self, _: Option<usize>) -> value_bag_sval2::lib::Result {
         self.text_buf.clear();
         Ok(())
@@ -325,6 +494,25 @@ impl<'a, 'v> value_bag_sval2::lib::Stream<'v> for VisitorStream<'a, 'v> {
     }
 }
 
+/*
+The contents of `sval` tags aren't public, so only the well-known ones
+can be given a name.
+*/
+fn tag_name(tag: &value_bag_sval2::lib::Tag) -> Option<&'static str> {
+    use value_bag_sval2::lib::tags;
+
+    match *tag {
+        tags::RUST_OPTION_SOME => Some("RUST_OPTION_SOME"),
+        tags::RUST_OPTION_NONE => Some("RUST_OPTION_NONE"),
+        tags::RUST_UNIT => Some("RUST_UNIT"),
+        tags::NUMBER => Some("NUMBER"),
+        tags::CONSTANT_SIZE => Some("CONSTANT_SIZE"),
+        tags::VALUE_IDENT => Some("VALUE_IDENT"),
+        tags::VALUE_OFFSET => Some("VALUE_OFFSET"),
+        _ => None,
+    }
+}
+
 impl Error {
     pub(in crate::internal) fn from_sval2(_: value_bag_sval2::lib::Error) -> Self {
         Error::msg("`sval` serialization failed")
@@ -335,6 +523,62 @@ impl Error {
     }
 }
 
+#[cfg(feature = "map")]
+pub(crate) mod map {
+    use super::*;
+
+    use crate::{internal::map::Visitor, std::ops::ControlFlow};
+
+    pub(super) struct StreamVisitor<'a, S: ?Sized> {
+        pub(super) stream: &'a mut S,
+        pub(super) err: Option<value_bag_sval2::lib::Error>,
+    }
+
+    impl<'a, 'sval, S: value_bag_sval2::lib::Stream<'sval> + ?Sized> StreamVisitor<'a, S> {
+        fn try_entry(
+            &mut self,
+            k: impl FnOnce(&mut S) -> value_bag_sval2::lib::Result,
+            v: impl FnOnce(&mut S) -> value_bag_sval2::lib::Result,
+        ) -> ControlFlow<()> {
+            let r = (|| {
+                self.stream.map_key_begin()?;
+                k(&mut *self.stream)?;
+                self.stream.map_key_end()?;
+
+                self.stream.map_value_begin()?;
+                v(&mut *self.stream)?;
+                self.stream.map_value_end()
+            })();
+
+            match r {
+                Ok(()) => ControlFlow::Continue(()),
+                Err(e) => {
+                    self.err = Some(e);
+                    ControlFlow::Break(())
+                }
+            }
+        }
+    }
+
+    impl<'a, 'sval, S: value_bag_sval2::lib::Stream<'sval> + ?Sized> Visitor<'sval>
+        for StreamVisitor<'a, S>
+    {
+        fn entry(&mut self, k: ValueBag, v: ValueBag) -> ControlFlow<()> {
+            self.try_entry(
+                |s| value_bag_sval2::lib::stream_computed(s, k),
+                |s| value_bag_sval2::lib::stream_computed(s, v),
+            )
+        }
+
+        fn borrowed_entry(&mut self, k: ValueBag<'sval>, v: ValueBag<'sval>) -> ControlFlow<()> {
+            self.try_entry(
+                |s| value_bag_sval2::lib_ref::stream_ref(s, k),
+                |s| value_bag_sval2::lib_ref::stream_ref(s, v),
+            )
+        }
+    }
+}
+
 #[cfg(feature = "seq")]
 pub(crate) mod seq {
     use super::*;
@@ -392,8 +636,9 @@ pub(crate) mod seq {
     }
 
     #[inline]
-    pub(crate) fn extend<'a, 'b, S: Default + ExtendValue<'a>>(v: &'b dyn Value) -> Option<S> {
+    pub(crate) fn extend<'a, 'b, S: ExtendValue<'a>>(seq: S, v: &'b dyn Value) -> Option<S> {
         let mut stream = Root {
+            seed: Some(seq),
             seq: None,
             text_buf: Default::default(),
             depth: 0,
@@ -405,8 +650,9 @@ pub(crate) mod seq {
     }
 
     #[inline]
-    pub(crate) fn extend_borrowed<'a, S: Default + ExtendValue<'a>>(v: &'a dyn Value) -> Option<S> {
+    pub(crate) fn extend_borrowed<'a, S: ExtendValue<'a>>(seq: S, v: &'a dyn Value) -> Option<S> {
         let mut stream = Root {
+            seed: Some(seq),
             seq: None,
             text_buf: Default::default(),
             depth: 0,
@@ -418,6 +664,7 @@ pub(crate) mod seq {
     }
 
     struct Root<'v, S> {
+        seed: Option<S>,
         seq: Option<S>,
         text_buf: value_bag_sval2::buffer::TextBuf<'v>,
         depth: usize,
@@ -459,9 +706,7 @@ pub(crate) mod seq {
         }
     }
 
-    impl<'sval, S: Default + ExtendValue<'sval>> value_bag_sval2::lib::Stream<'sval>
-        for Root<'sval, S>
-    {
+    impl<'sval, S: ExtendValue<'sval>> value_bag_sval2::lib::Stream<'sval> for Root<'sval, S> {
         fn null(&mut self) -> value_bag_sval2::lib::Result {
             extend_borrowed_internal(self.seq.as_mut(), self.depth, ())
         }
@@ -532,7 +777,7 @@ pub(crate) mod seq {
 
         fn seq_begin(&mut self, _: Option<usize>) -> value_bag_sval2::lib::Result {
             if self.seq.is_none() {
-                self.seq = Some(S::default());
+                self.seq = self.seed.take();
             }
 
             self.depth += 1;
@@ -729,6 +974,19 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn sval2_downcast_primitive() {
+        assert_eq!(
+            Some(&true),
+            ValueBag::capture_sval2(&true).downcast_ref::<bool>()
+        );
+        assert_eq!(
+            Some(&'a'),
+            ValueBag::capture_sval2(&'a').downcast_ref::<char>()
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn sval2_structured() {
@@ -737,6 +995,18 @@ mod tests {
         value_bag_sval2::test::assert_tokens(&value, &[value_bag_sval2::test::Token::U64(42)]);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn sval2_bytes() {
+        let value = ValueBag::from_bytes(&[1, 2, 3]);
+
+        value_bag_sval2::test::assert_tokens(&value, {
+            use value_bag_sval2::test::Token::*;
+
+            &[BinaryBegin(Some(3)), BinaryFragment(&[1, 2, 3]), BinaryEnd]
+        });
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn sval2_debug() {
@@ -757,6 +1027,22 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn sval2_debug_pretty() {
+        let value = [[1u8, 2], [3, 4]];
+
+        assert_eq!(
+            format!("{:#?}", value),
+            format!("{:#?}", ValueBag::from_sval2(&value)),
+        );
+        assert_eq!(
+            format!("{:?}", value),
+            format!("{:?}", ValueBag::from_sval2(&value)),
+        );
+        assert_eq!("[]", format!("{:#?}", ValueBag::from_sval2(&[0u8; 0])));
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn sval2_visit() {
@@ -839,6 +1125,15 @@ mod tests {
             });
         }
 
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn sval2_first_some() {
+            // The string is only available for a short lifetime, so it's skipped
+            let value = ValueBag::from_fill(&|slot: Slot| slot.fill_sval2(("a string", 1u64)));
+
+            assert_eq!(Some(1), value.first_some().and_then(|v| v.to_u64()));
+        }
+
         #[test]
         #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
         fn sval2_stream_str_seq() {
@@ -963,4 +1258,40 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "map")]
+    mod map_support {
+        use super::*;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn sval2_stream_map() {
+            let value = ValueBag::from_entries(&[("a", 1), ("b", 2)]);
+
+            value_bag_sval2::test::assert_tokens(&value, {
+                use value_bag_sval2::test::Token::*;
+
+                &[
+                    MapBegin(None),
+                    MapKeyBegin,
+                    TextBegin(Some(1)),
+                    TextFragment("a"),
+                    TextEnd,
+                    MapKeyEnd,
+                    MapValueBegin,
+                    I64(1),
+                    MapValueEnd,
+                    MapKeyBegin,
+                    TextBegin(Some(1)),
+                    TextFragment("b"),
+                    TextEnd,
+                    MapKeyEnd,
+                    MapValueBegin,
+                    I64(2),
+                    MapValueEnd,
+                    MapEnd,
+                ]
+            });
+        }
+    }
 }
@@ -0,0 +1,136 @@
+//! Integration between `Value` and `core::time`.
+//!
+//! Durations are captured as their total number of nanoseconds,
+//! so they're available without the standard library.
+
+use crate::{
+    fill::{Fill, Slot},
+    std::time::Duration,
+    Error, ValueBag,
+};
+
+#[cfg(feature = "std")]
+use crate::std::time::Instant;
+
+use super::Internal;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+impl<'v> ValueBag<'v> {
+    /// Get a value from a duration.
+    ///
+    /// The duration will be captured as its total number of nanoseconds
+    /// as a `u128`.
+    pub const fn from_duration(value: &'v Duration) -> Self {
+        ValueBag {
+            inner: Internal::Fill(DurationNanos::new_ref(value)),
+        }
+    }
+
+    /// Try get a duration from this value.
+    ///
+    /// This method will return `Some` if the value is a non-negative
+    /// number of nanoseconds that fits in a `Duration`.
+    pub fn to_duration(&self) -> Option<Duration> {
+        let nanos = self.to_u128()?;
+
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+        let subsec_nanos = (nanos % NANOS_PER_SEC) as u32;
+
+        Some(Duration::new(secs, subsec_nanos))
+    }
+
+    /// Get a value from the time elapsed between an instant and a later one.
+    ///
+    /// An `Instant` on its own has no absolute meaning, so the value captures the
+    /// elapsed duration from `instant` to `now` as a number of nanoseconds that can
+    /// be converted back using [`ValueBag::to_duration`]. If `now` is earlier than
+    /// `instant` then the elapsed duration is zero. Durations that don't fit in a
+    /// `u64` number of nanoseconds are saturated.
+    #[cfg(feature = "std")]
+    pub fn from_instant_elapsed(instant: Instant, now: Instant) -> Self {
+        let elapsed = now.saturating_duration_since(instant);
+
+        ValueBag::from_u64(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX))
+    }
+}
+
+#[repr(transparent)]
+struct DurationNanos(Duration);
+
+impl DurationNanos {
+    const fn new_ref(v: &Duration) -> &DurationNanos {
+        // SAFETY: `DurationNanos` and `Duration` have the same ABI
+        unsafe { &*(v as *const Duration as *const DurationNanos) }
+    }
+}
+
+impl Fill for DurationNanos {
+    fn fill(&self, slot: Slot) -> Result<(), Error> {
+        slot.fill(|visitor| visitor.u128(&self.0.as_nanos()))
+    }
+}
+
+impl<'v> From<&'v Duration> for ValueBag<'v> {
+    #[inline]
+    fn from(v: &'v Duration) -> Self {
+        ValueBag::from_duration(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::test::TestToken;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn duration_capture() {
+        let duration = Duration::new(5, 30);
+
+        assert_eq!(
+            TestToken::U128(5_000_000_030),
+            ValueBag::from_duration(&duration).to_test_token()
+        );
+        assert_eq!(Some(5_000_000_030), ValueBag::from(&duration).to_u128());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn duration_cast() {
+        let duration = Duration::new(5, 30);
+
+        assert_eq!(
+            Some(duration),
+            ValueBag::from_duration(&duration).to_duration()
+        );
+        assert_eq!(
+            Some(Duration::from_micros(1)),
+            ValueBag::from(1_000u64).to_duration()
+        );
+
+        assert_eq!(None, ValueBag::from(-1i64).to_duration());
+        assert_eq!(None, ValueBag::from(&u128::MAX).to_duration());
+        assert_eq!(None, ValueBag::from("5s").to_duration());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    fn instant_elapsed_capture() {
+        let start = Instant::now();
+        let now = start + Duration::from_millis(1_500);
+
+        let value = ValueBag::from_instant_elapsed(start, now);
+
+        assert_eq!(Some(1_500_000_000), value.to_u64());
+        assert_eq!(Some(Duration::from_millis(1_500)), value.to_duration());
+
+        assert_eq!(
+            Some(Duration::ZERO),
+            ValueBag::from_instant_elapsed(now, start).to_duration()
+        );
+    }
+}
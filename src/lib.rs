@@ -9,6 +9,11 @@
 
 #![doc(html_root_url = "https://docs.rs/value-bag/1.10.0")]
 #![no_std]
+#![cfg_attr(value_bag_unstable_ascii_char, feature(ascii_char))]
+#![cfg_attr(
+    all(value_bag_unstable_error_backtrace, feature = "error"),
+    feature(error_generic_member_access)
+)]
 #![allow(
     clippy::unnecessary_fallible_conversions,
     clippy::explicit_auto_deref,
@@ -98,11 +103,11 @@ extern crate alloc;
 #[allow(unused_imports)]
 mod std {
     pub use crate::{
-        alloc::{borrow, boxed, string, vec},
+        alloc::{borrow, boxed, collections, rc, string, vec},
         core::*,
     };
 
-    #[cfg(feature = "owned")]
+    #[cfg(any(feature = "owned", target_has_atomic = "ptr"))]
     pub use crate::alloc::sync;
 }
 
@@ -127,6 +132,17 @@ pub use self::owned::*;
 
 pub use self::error::Error;
 
+#[cfg(feature = "std")]
+pub use self::internal::any::CapturePrimitive;
+
+pub use self::internal::intern::Interner;
+
+#[cfg(feature = "seq")]
+pub use self::internal::seq::MapSeq;
+
+#[cfg(feature = "map")]
+pub use self::internal::map::LabeledTuple;
+
 /// A dynamic structured value.
 ///
 /// # Capturing values
@@ -439,6 +455,35 @@ pub use self::error::Error;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// # Working with maps
+///
+/// The `map` feature of `value-bag` enables utilities for working with values that are maps.
+/// First, enable the `map` feature in your `Cargo.toml`:
+///
+/// ```toml
+/// [dependencies.value-bag]
+/// features = ["map"]
+/// ```
+///
+/// Slices of key-value pairs can be captured as maps, and have their entries extracted:
+///
+/// ```
+/// # #[cfg(not(all(feature = "std", feature = "map")))] fn main() {}
+/// # #[cfg(all(feature = "std", feature = "map"))]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use value_bag::ValueBag;
+///
+/// let value = ValueBag::from_entries(&[("a", 1), ("b", 2)]);
+///
+/// let map = value
+///     .to_map::<String, i64, Vec<(Option<String>, Option<i64>)>>()
+///     .ok_or("not a map")?;
+///
+/// assert_eq!(vec![(Some("a".into()), Some(1)), (Some("b".into()), Some(2))], map);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone)]
 pub struct ValueBag<'v> {
     inner: internal::Internal<'v>,
@@ -608,6 +653,17 @@ impl<'v> ValueBag<'v> {
         }
     }
 
+    /// Get a `ValueBag` from a `bool` as an integer.
+    ///
+    /// The value will be captured as `1` for `true` and `0` for `false`
+    /// instead of as a `bool`.
+    #[inline]
+    pub const fn from_bool_as_int(v: bool) -> ValueBag<'v> {
+        ValueBag {
+            inner: internal::Internal::Unsigned(v as u64),
+        }
+    }
+
     /// Get a `ValueBag` from a `str`.
     #[inline]
     pub const fn from_str(v: &'v str) -> ValueBag<'v> {
@@ -616,6 +672,28 @@ impl<'v> ValueBag<'v> {
         }
     }
 
+    /// Get a `ValueBag` from a `str`, truncated to at most `max` bytes.
+    ///
+    /// If `max` falls within a multi-byte character then the string is truncated
+    /// before that character, so the captured string may be shorter than `max`.
+    #[inline]
+    pub fn from_str_capped(v: &'v str, max: usize) -> ValueBag<'v> {
+        let mut len = v.len().min(max);
+        while !v.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        ValueBag::from_str(&v[..len])
+    }
+
+    /// Get a `ValueBag` from a byte buffer.
+    #[inline]
+    pub const fn from_bytes(v: &'v [u8]) -> ValueBag<'v> {
+        ValueBag {
+            inner: internal::Internal::Bytes(v),
+        }
+    }
+
     /// Get a `ValueBag` from a `char`.
     #[inline]
     pub const fn from_char(v: char) -> ValueBag<'v> {
@@ -624,6 +702,17 @@ impl<'v> ValueBag<'v> {
         }
     }
 
+    /// Get a `ValueBag` from an `Ordering`.
+    ///
+    /// The value will be captured as `-1` for `Less`, `0` for `Equal`, and `1` for `Greater`.
+    /// When formatted using `Debug` or `Display`, the name of the variant is used instead.
+    #[inline]
+    pub const fn from_ordering(v: crate::std::cmp::Ordering) -> ValueBag<'v> {
+        ValueBag {
+            inner: internal::Internal::Fill(internal::fmt::OrderingName::new_ref(v)),
+        }
+    }
+
     /// Get a `ValueBag` from a reference to a `ValueBag`.
     #[inline]
     pub const fn by_ref(&self) -> ValueBag<'_> {
@@ -631,12 +720,64 @@ impl<'v> ValueBag<'v> {
             inner: self.inner.by_ref(),
         }
     }
+
+    /// Try convert this value into a `ValueBag<'static>` without allocating.
+    ///
+    /// This method will succeed for primitive numbers, booleans, characters, and
+    /// empty values, which don't borrow any data. Any other value, including strings,
+    /// is handed back unchanged in the `Err` variant.
+    pub fn try_into_static(self) -> Result<ValueBag<'static>, Self> {
+        let inner = match self.inner {
+            internal::Internal::Signed(v) => internal::Internal::Signed(v),
+            internal::Internal::Unsigned(v) => internal::Internal::Unsigned(v),
+            #[cfg(feature = "inline-i128")]
+            internal::Internal::BigSigned(v) => internal::Internal::BigSigned(v),
+            #[cfg(feature = "inline-i128")]
+            internal::Internal::BigUnsigned(v) => internal::Internal::BigUnsigned(v),
+            internal::Internal::Float(v) => internal::Internal::Float(v),
+            internal::Internal::Bool(v) => internal::Internal::Bool(v),
+            internal::Internal::Char(v) => internal::Internal::Char(v),
+            internal::Internal::None => internal::Internal::None,
+            internal::Internal::Primitive(v) => {
+                return ValueBag {
+                    inner: v.to_internal(),
+                }
+                .try_into_static()
+                .map_err(|_| self)
+            }
+            _ => return Err(self),
+        };
+
+        Ok(ValueBag { inner })
+    }
+
+    /// Get a value from another value, marked with a boolean flag.
+    ///
+    /// The flag can be read back using [`ValueBag::flag`]. It's otherwise
+    /// invisible, so the value behaves the same as the one it was created from.
+    /// This can be useful for marking fields, such as ones that were sampled.
+    pub const fn from_flagged(flag: bool, value: &'v ValueBag<'v>) -> Self {
+        if flag {
+            ValueBag {
+                inner: internal::Internal::Flagged(&value.inner),
+            }
+        } else {
+            value.by_ref()
+        }
+    }
+
+    /// Check whether this value was marked using [`ValueBag::from_flagged`].
+    ///
+    /// The flag isn't retained when the value is buffered.
+    pub const fn flag(&self) -> bool {
+        matches!(self.inner, internal::Internal::Flagged(_))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::std::mem;
+    use crate::{std::mem, test::TestToken};
 
     #[cfg(feature = "inline-i128")]
     const SIZE_LIMIT_U64: usize = 4;
@@ -655,4 +796,71 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn bool_as_int() {
+        assert_eq!(Some(1), ValueBag::from_bool_as_int(true).to_u64());
+        assert_eq!(Some(0), ValueBag::from_bool_as_int(false).to_u64());
+
+        assert_eq!(None, ValueBag::from_bool_as_int(true).to_bool());
+        assert_eq!(
+            TestToken::U64(1),
+            ValueBag::from_bool_as_int(true).to_test_token()
+        );
+
+        assert_eq!(Some(true), ValueBag::from(true).to_bool());
+    }
+
+    #[test]
+    fn str_capped() {
+        assert_eq!(
+            Some("a lo"),
+            ValueBag::from_str_capped("a long string", 4).to_borrowed_str()
+        );
+        assert_eq!(
+            Some("short"),
+            ValueBag::from_str_capped("short", 10).to_borrowed_str()
+        );
+
+        // `é` is 2 bytes, so a cap of 2 would split it
+        assert_eq!(
+            Some("a"),
+            ValueBag::from_str_capped("aé", 2).to_borrowed_str()
+        );
+        assert_eq!(
+            Some("aé"),
+            ValueBag::from_str_capped("aé", 3).to_borrowed_str()
+        );
+    }
+
+    #[test]
+    fn flagged() {
+        let sampled = ValueBag::from(42u64);
+
+        let value = ValueBag::from_flagged(true, &sampled);
+        assert!(value.flag());
+        assert!(value.by_ref().flag());
+        assert_eq!(Some(42), value.to_u64());
+
+        let value = ValueBag::from_flagged(false, &sampled);
+        assert!(!value.flag());
+        assert_eq!(Some(42), value.to_u64());
+
+        assert!(!sampled.flag());
+    }
+
+    #[test]
+    fn try_into_static() {
+        let value: ValueBag<'static> = ValueBag::from(5u64).try_into_static().unwrap();
+        assert_eq!(Some(5), value.to_u64());
+
+        assert!(ValueBag::empty().try_into_static().unwrap().is_empty());
+
+        #[cfg(feature = "alloc")]
+        {
+            let local = crate::std::string::String::from("a string");
+            let value = ValueBag::from(&local).try_into_static().unwrap_err();
+            assert_eq!(Some("a string"), value.to_borrowed_str());
+        }
+    }
 }
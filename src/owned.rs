@@ -1,6 +1,12 @@
 use crate::{
     internal::{self, Internal},
-    std::sync::Arc,
+    std::{
+        borrow::{Cow, ToOwned},
+        boxed::Box,
+        string::{String, ToString},
+        sync::Arc,
+        vec::Vec,
+    },
     ValueBag,
 };
 
@@ -30,6 +36,68 @@ impl<'v> ValueBag<'v> {
     pub fn to_shared(&self) -> OwnedValueBag {
         self.to_owned().into_shared()
     }
+
+    /// Buffer the `Debug` representation of this value into an [`OwnedValueBag`].
+    ///
+    /// Unlike [`ValueBag::to_owned`], which preserves the structure of values like
+    /// primitives and sequences, this method always buffers the formatted string.
+    /// The owned value will format the same way using either `Debug` or `Display`.
+    pub fn to_owned_debug(&self) -> OwnedValueBag {
+        OwnedValueBag {
+            inner: internal::owned::OwnedInternal::Debug(internal::fmt::owned::buffer_debug(self)),
+        }
+    }
+
+    /// Buffer the `Display` representation of this value into an [`OwnedValueBag`]
+    /// using the given width and precision.
+    ///
+    /// [`ValueBag::to_owned`] renders values like [`ValueBag::from_args`] with default
+    /// formatting flags. This method renders the value as if it were formatted using
+    /// `{:width$.precision$}` instead. Values that ignore the width while formatting are
+    /// padded with trailing spaces.
+    pub fn to_owned_with_flags(
+        &self,
+        width: Option<usize>,
+        precision: Option<usize>,
+    ) -> OwnedValueBag {
+        let rendered = match (width, precision) {
+            (Some(width), Some(precision)) => format!("{:width$.precision$}", self),
+            (Some(width), None) => format!("{:width$}", self),
+            (None, Some(precision)) => format!("{:.precision$}", self),
+            (None, None) => self.to_string(),
+        };
+
+        let rendered = match width {
+            Some(width) => format!("{:width$}", rendered),
+            None => rendered,
+        };
+
+        OwnedValueBag::from_boxed_str(rendered.into())
+    }
+
+    /// Concatenate the elements of this value and another into an owned sequence.
+    ///
+    /// If either value is not a sequence then this method will return `None`.
+    #[cfg(feature = "seq")]
+    pub fn concat(&self, other: &ValueBag) -> Option<OwnedValueBag> {
+        Some(OwnedValueBag {
+            inner: internal::owned::OwnedInternal::Seq(internal::seq::owned::concat(
+                &self.inner,
+                &other.inner,
+            )?),
+        })
+    }
+
+    /// Render the `Display` representation of this value once into an [`OwnedValueBag`].
+    ///
+    /// The rendered string is stored in an `Arc`, so the owned value can be inspected
+    /// and cloned any number of times without rendering the original value again.
+    /// The owned value can be converted into a string using [`ValueBag::to_borrowed_str`].
+    pub fn to_owned_cached(&self) -> OwnedValueBag {
+        OwnedValueBag {
+            inner: internal::owned::OwnedInternal::SharedStr(Arc::from(self.to_string())),
+        }
+    }
 }
 
 impl ValueBag<'static> {
@@ -146,6 +214,70 @@ impl ValueBag<'static> {
 }
 
 impl OwnedValueBag {
+    /// Get a value from an owned string.
+    ///
+    /// The string is moved into the value without allocating.
+    pub fn from_boxed_str(value: Box<str>) -> Self {
+        OwnedValueBag {
+            inner: internal::owned::OwnedInternal::Str(value),
+        }
+    }
+
+    /// Get a value from an owned byte buffer.
+    ///
+    /// The buffer is moved into the value without allocating.
+    pub fn from_boxed_bytes(value: Box<[u8]>) -> Self {
+        OwnedValueBag {
+            inner: internal::owned::OwnedInternal::Bytes(value),
+        }
+    }
+
+    /// Get a value from a borrowed type by converting it into its owned form.
+    ///
+    /// The value is converted using its [`ToOwned`] implementation, and the result
+    /// is then moved into the value.
+    pub fn from_to_owned<T>(value: &T) -> Self
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: Into<OwnedValueBag>,
+    {
+        value.to_owned().into()
+    }
+
+    /// Get a value from a `Cow`, like a `Cow<str>` or `Cow<[u8]>`.
+    ///
+    /// Borrowed data is copied into the value. Owned data is moved into the
+    /// value, so its buffer is reused without copying as long as it doesn't have
+    /// any spare capacity. Buffers with spare capacity are shrunk to fit first,
+    /// which may reallocate.
+    pub fn from_cow<T>(value: Cow<T>) -> Self
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: Into<OwnedValueBag>,
+    {
+        match value {
+            Cow::Borrowed(value) => OwnedValueBag::from_to_owned(value),
+            Cow::Owned(value) => value.into(),
+        }
+    }
+
+    /// Get a value from an iterator by buffering its items into an owned sequence.
+    ///
+    /// The iterator is consumed, so its items don't need to outlive the value.
+    /// Each item is converted into a [`ValueBag`] and then buffered.
+    #[cfg(feature = "seq")]
+    pub fn from_iter_owned<'v, I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ValueBag<'v>>,
+    {
+        OwnedValueBag {
+            inner: internal::owned::OwnedInternal::Seq(internal::seq::owned::collect(
+                iter.into_iter().map(|item| item.into().to_owned()),
+            )),
+        }
+    }
+
     /// Get a regular [`ValueBag`] from this type.
     ///
     /// Once a `ValueBag` has been buffered, it will behave
@@ -154,7 +286,7 @@ impl OwnedValueBag {
     /// - `fmt::Debug` won't use formatting flags.
     /// - `serde::Serialize` will use the text-based representation.
     /// - The original type may change, so downcasting can stop producing results.
-    pub const fn by_ref(&self) -> ValueBag {
+    pub const fn by_ref(&self) -> ValueBag<'_> {
         ValueBag {
             inner: self.inner.by_ref(),
         }
@@ -170,7 +302,51 @@ impl OwnedValueBag {
     }
 }
 
+impl From<Box<str>> for OwnedValueBag {
+    #[inline]
+    fn from(v: Box<str>) -> Self {
+        OwnedValueBag::from_boxed_str(v)
+    }
+}
+
+impl From<Box<[u8]>> for OwnedValueBag {
+    #[inline]
+    fn from(v: Box<[u8]>) -> Self {
+        OwnedValueBag::from_boxed_bytes(v)
+    }
+}
+
+impl From<String> for OwnedValueBag {
+    #[inline]
+    fn from(v: String) -> Self {
+        OwnedValueBag::from_boxed_str(v.into_boxed_str())
+    }
+}
+
+impl From<Vec<u8>> for OwnedValueBag {
+    #[inline]
+    fn from(v: Vec<u8>) -> Self {
+        OwnedValueBag::from_boxed_bytes(v.into_boxed_slice())
+    }
+}
+
+impl<'v> From<Cow<'v, str>> for OwnedValueBag {
+    #[inline]
+    fn from(v: Cow<'v, str>) -> Self {
+        OwnedValueBag::from_cow(v)
+    }
+}
+
+impl<'v> From<Cow<'v, [u8]>> for OwnedValueBag {
+    #[inline]
+    fn from(v: Cow<'v, [u8]>) -> Self {
+        OwnedValueBag::from_cow(v)
+    }
+}
+
 #[cfg(test)]
+// `io::Error::other` isn't available on all the compilers we test with
+#[allow(clippy::io_other_error)]
 mod tests {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::*;
@@ -182,7 +358,8 @@ mod tests {
         std::{mem, string::ToString},
     };
 
-    const SIZE_LIMIT_U64: usize = 4;
+    const SIZE_LIMIT_U64: usize = 3;
+    const VARIANT_SIZE_LIMIT_U64: usize = 2;
 
     #[test]
     fn is_send_sync() {
@@ -204,6 +381,154 @@ mod tests {
         }
     }
 
+    #[test]
+    fn owned_value_bag_variant_size() {
+        fn assert_size<T>(variant: &str) {
+            let size = mem::size_of::<T>();
+            let limit = mem::size_of::<u64>() * VARIANT_SIZE_LIMIT_U64;
+
+            if size > limit {
+                panic!(
+                    "`OwnedInternal::{}` size ({} bytes) is too large (expected up to {} bytes)",
+                    variant, size, limit,
+                );
+            }
+        }
+
+        assert_size::<i64>("Signed");
+        assert_size::<u64>("Unsigned");
+        assert_size::<f64>("Float");
+        assert_size::<Box<str>>("Str");
+        assert_size::<Box<[u8]>>("Bytes");
+        assert_size::<Box<i128>>("BigSigned");
+        assert_size::<Box<u128>>("BigUnsigned");
+        assert_size::<internal::fmt::owned::OwnedFmt>("Debug");
+        assert_size::<internal::fmt::owned::OwnedFmt>("Display");
+        #[cfg(feature = "error")]
+        assert_size::<Box<internal::error::owned::OwnedError>>("Error");
+        #[cfg(feature = "serde1")]
+        assert_size::<internal::serde::v1::owned::OwnedSerialize>("Serde1");
+        #[cfg(feature = "sval2")]
+        assert_size::<Box<internal::sval::v2::owned::OwnedValue>>("Sval2");
+        #[cfg(feature = "seq")]
+        assert_size::<internal::seq::owned::OwnedSeq>("Seq");
+        #[cfg(feature = "map")]
+        assert_size::<internal::map::owned::OwnedMap>("Map");
+        assert_size::<Arc<str>>("SharedStr");
+        assert_size::<Arc<dyn internal::fmt::DowncastDebug + Send + Sync>>("SharedDebug");
+        assert_size::<Arc<dyn internal::fmt::DowncastDisplay + Send + Sync>>("SharedDisplay");
+        #[cfg(feature = "error")]
+        assert_size::<Arc<dyn internal::error::DowncastError + Send + Sync>>("SharedError");
+        #[cfg(feature = "serde1")]
+        assert_size::<Arc<dyn internal::serde::v1::DowncastSerialize + Send + Sync>>(
+            "SharedSerde1",
+        );
+        #[cfg(feature = "sval2")]
+        assert_size::<Arc<dyn internal::sval::v2::DowncastValue + Send + Sync>>("SharedSval2");
+        #[cfg(feature = "seq")]
+        assert_size::<Arc<dyn internal::seq::DowncastSeq + Send + Sync>>("SharedSeq");
+        #[cfg(feature = "map")]
+        assert_size::<Arc<dyn internal::map::DowncastMap + Send + Sync>>("SharedMap");
+        assert_size::<&'static str>("Poisoned");
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn boxed_str() {
+        let value = OwnedValueBag::from(Box::<str>::from("a string"));
+
+        assert_eq!("a string", value.by_ref().to_str().unwrap());
+        assert_eq!(Some("a string"), value.by_ref().to_borrowed_str());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn boxed_bytes() {
+        let value = OwnedValueBag::from(Box::<[u8]>::from(&b"bytes"[..]));
+
+        assert_eq!(&b"bytes"[..], &*value.by_ref().to_bytes().unwrap());
+        assert_eq!(Some(&b"bytes"[..]), value.by_ref().to_borrowed_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn cow_bytes() {
+        let borrowed = OwnedValueBag::from(Cow::Borrowed(&b"bytes"[..]));
+        let owned = OwnedValueBag::from(Cow::<[u8]>::Owned(b"bytes".to_vec()));
+
+        assert_eq!(Some(&b"bytes"[..]), borrowed.by_ref().to_borrowed_bytes());
+        assert_eq!(Some(&b"bytes"[..]), owned.by_ref().to_borrowed_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn cow_str() {
+        let borrowed = OwnedValueBag::from(Cow::Borrowed("a string"));
+        let owned = OwnedValueBag::from(Cow::<str>::Owned("a string".to_string()));
+
+        assert_eq!(Some("a string"), borrowed.by_ref().to_borrowed_str());
+        assert_eq!(Some("a string"), owned.by_ref().to_borrowed_str());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn cow_owned_retained() {
+        // Buffers are only reused when they don't have any spare capacity
+        let string = String::from("a string");
+        assert_eq!(string.len(), string.capacity());
+        let ptr = string.as_ptr();
+
+        let value = OwnedValueBag::from(Cow::<str>::Owned(string));
+        assert_eq!(ptr, value.by_ref().to_borrowed_str().unwrap().as_ptr());
+
+        let string = String::from("a string");
+        let ptr = string.as_ptr();
+
+        let value = OwnedValueBag::from(string);
+        assert_eq!(ptr, value.by_ref().to_borrowed_str().unwrap().as_ptr());
+
+        let mut string = String::with_capacity(16);
+        string.push_str("a string");
+
+        let value = OwnedValueBag::from(string);
+        assert_eq!(Some("a string"), value.by_ref().to_borrowed_str());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn from_to_owned() {
+        use crate::std::borrow::Borrow;
+
+        struct Celsius(f64);
+        struct OwnedCelsius(Celsius);
+
+        impl Borrow<Celsius> for OwnedCelsius {
+            fn borrow(&self) -> &Celsius {
+                &self.0
+            }
+        }
+
+        impl ToOwned for Celsius {
+            type Owned = OwnedCelsius;
+
+            fn to_owned(&self) -> OwnedCelsius {
+                OwnedCelsius(Celsius(self.0))
+            }
+        }
+
+        impl From<OwnedCelsius> for OwnedValueBag {
+            fn from(v: OwnedCelsius) -> Self {
+                ValueBag::from(v.0 .0).to_owned()
+            }
+        }
+
+        let value = OwnedValueBag::from_to_owned(&Celsius(21.5));
+        assert_eq!(Some(21.5), value.by_ref().to_f64());
+
+        let value = OwnedValueBag::from_cow(Cow::Borrowed(&Celsius(21.5)));
+        assert_eq!(Some(21.5), value.by_ref().to_f64());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn fill_to_owned() {
@@ -211,7 +536,7 @@ mod tests {
 
         assert!(matches!(
             value.inner,
-            internal::owned::OwnedInternal::BigUnsigned(42)
+            internal::owned::OwnedInternal::Unsigned(42)
         ));
     }
 
@@ -222,7 +547,7 @@ mod tests {
 
         assert!(matches!(
             value.inner,
-            internal::owned::OwnedInternal::BigUnsigned(42)
+            internal::owned::OwnedInternal::Unsigned(42)
         ));
     }
 
@@ -267,6 +592,71 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_to_owned_debug() {
+        let value = ValueBag::from("a value").to_owned_debug();
+
+        assert!(matches!(
+            value.inner,
+            internal::owned::OwnedInternal::Debug(_)
+        ));
+
+        assert_eq!("\"a value\"", value.to_string());
+        assert_eq!("\"a value\"", format!("{:?}", value));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_to_owned_cached() {
+        use crate::std::{
+            fmt,
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+
+        struct Counting(AtomicUsize);
+
+        impl fmt::Display for Counting {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                f.write_str("a value")
+            }
+        }
+
+        let counting = Counting(AtomicUsize::new(0));
+        let value = ValueBag::from_display(&counting).to_owned_cached();
+
+        assert_eq!("a value", value.to_string());
+        assert_eq!(Some("a value"), value.clone().by_ref().to_borrowed_str());
+
+        assert_eq!(1, counting.0.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn fmt_to_owned_with_flags() {
+        let value = ValueBag::from_args(&format_args!("{}", 42)).to_owned_with_flags(Some(5), None);
+        assert_eq!(Some("42   "), value.by_ref().to_borrowed_str());
+
+        let value = ValueBag::from(1.2345f64).to_owned_with_flags(Some(6), Some(2));
+        assert_eq!(Some("  1.23"), value.by_ref().to_borrowed_str());
+
+        let value = ValueBag::from("a string").to_owned_with_flags(None, Some(1));
+        assert_eq!(Some("a"), value.by_ref().to_borrowed_str());
+
+        let value = ValueBag::from_args(&format_args!("{}", 42)).to_owned();
+        assert_eq!("42", value.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "seq")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn seq_to_owned_debug() {
+        let value = ValueBag::from(&[1, 2, 3]);
+
+        assert_eq!(format!("{:?}", value), value.to_owned_debug().to_string());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn owned_fmt_to_owned() {
@@ -462,6 +852,66 @@ mod tests {
         assert!(matches!(value.inner, internal::Internal::AnonSeq(_)));
     }
 
+    #[test]
+    #[cfg(feature = "seq")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn seq_to_owned_round_trip() {
+        use crate::std::vec::Vec;
+
+        let expected = Some(vec![Some(1), Some(2), Some(3)]);
+
+        for value in [
+            ValueBag::from_seq_slice(&[1, 2, 3]).to_owned(),
+            ValueBag::from_seq_slice(&[1, 2, 3]).to_shared(),
+            ValueBag::from_fill(&|slot: fill::Slot| slot.fill_seq_slice(&[1, 2, 3])).to_owned(),
+            ValueBag::from_seq_slice(&[1, 2, 3])
+                .to_owned()
+                .by_ref()
+                .to_owned(),
+        ] {
+            assert_eq!(expected, value.by_ref().to_u64_seq::<Vec<Option<u64>>>());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "seq")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn seq_from_iter_owned() {
+        use crate::std::vec::Vec;
+
+        let iter = vec![1u64, 2, 3].into_iter();
+        let value = OwnedValueBag::from_iter_owned(iter);
+
+        assert_eq!(
+            Some(vec![Some(1), Some(2), Some(3)]),
+            value.by_ref().to_u64_seq::<Vec<Option<u64>>>()
+        );
+
+        let value = OwnedValueBag::from_iter_owned(Vec::<bool>::new());
+
+        assert_eq!(Some(0), value.by_ref().len());
+    }
+
+    #[test]
+    #[cfg(feature = "seq")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn seq_concat() {
+        use crate::std::vec::Vec;
+
+        let a = ValueBag::from_seq_slice(&[1, 2]);
+        let b = ValueBag::from_seq_slice(&[3, 4, 5]);
+
+        let value = a.concat(&b).unwrap();
+
+        assert_eq!(
+            Some(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]),
+            value.by_ref().to_u64_seq::<Vec<Option<u64>>>()
+        );
+
+        assert!(a.concat(&ValueBag::from(1)).is_none());
+        assert!(ValueBag::from(1).concat(&b).is_none());
+    }
+
     #[test]
     #[cfg(feature = "seq")]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
@@ -489,4 +939,21 @@ mod tests {
 
         assert!(matches!(value.inner, internal::Internal::SharedRefSeq(_)));
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn shared_str_to_owned() {
+        let arc: Arc<str> = Arc::from("a shared string");
+
+        let value = ValueBag::from(&arc).to_owned();
+
+        match value.inner {
+            internal::owned::OwnedInternal::SharedStr(ref owned) => {
+                assert!(Arc::ptr_eq(&arc, owned))
+            }
+            _ => panic!("unexpected value"),
+        }
+
+        assert_eq!(Some("a shared string"), value.by_ref().to_borrowed_str());
+    }
 }
@@ -2,11 +2,13 @@
 
 use crate::{
     internal,
-    std::{fmt, str, string::String},
-    visit::Visit,
+    std::{fmt, str, string::String, vec::Vec},
     Error, ValueBag,
 };
 
+#[cfg(test)]
+use crate::visit::Visit;
+
 #[cfg(test)]
 pub(crate) trait IntoValueBag<'v> {
     fn into_value_bag(self) -> ValueBag<'v>;
@@ -36,6 +38,8 @@ pub enum TestToken {
     Char(char),
     Bool(bool),
     Str(String),
+    BorrowedStr(String),
+    Bytes(Vec<u8>),
     None,
 
     #[cfg(feature = "error")]
@@ -54,6 +58,9 @@ pub enum TestToken {
     #[cfg(feature = "seq")]
     Seq,
 
+    #[cfg(feature = "map")]
+    Map,
+
     Poisoned(String),
 }
 
@@ -64,7 +71,27 @@ impl<'v> ValueBag<'v> {
     This _isn't_ a general-purpose API for working with values outside of testing.
     */
     pub fn to_test_token(&self) -> TestToken {
-        struct TestVisitor(Option<TestToken>);
+        self.to_test_token_with(false)
+    }
+
+    /**
+    Convert the value bag into a token for testing, distinguishing borrowed strings.
+
+    Strings that are borrowed for the lifetime of the value bag are tokenized as
+    `TestToken::BorrowedStr`, rather than `TestToken::Str`. This is useful for
+    testing that borrowed data is preserved through conversions.
+
+    This _isn't_ a general-purpose API for working with values outside of testing.
+    */
+    pub fn to_test_token_detailed(&self) -> TestToken {
+        self.to_test_token_with(true)
+    }
+
+    fn to_test_token_with(&self, detailed: bool) -> TestToken {
+        struct TestVisitor {
+            token: Option<TestToken>,
+            detailed: bool,
+        }
 
         impl<'v> internal::InternalVisitor<'v> for TestVisitor {
             fn fill(&mut self, v: &dyn crate::fill::Fill) -> Result<(), Error> {
@@ -72,97 +99,121 @@ impl<'v> ValueBag<'v> {
             }
 
             fn debug(&mut self, v: &dyn fmt::Debug) -> Result<(), Error> {
-                self.0 = Some(TestToken::Str(format!("{:?}", v)));
+                self.token = Some(TestToken::Str(format!("{:?}", v)));
                 Ok(())
             }
 
             fn display(&mut self, v: &dyn fmt::Display) -> Result<(), Error> {
-                self.0 = Some(TestToken::Str(format!("{}", v)));
+                self.token = Some(TestToken::Str(format!("{}", v)));
                 Ok(())
             }
 
             fn u64(&mut self, v: u64) -> Result<(), Error> {
-                self.0 = Some(TestToken::U64(v));
+                self.token = Some(TestToken::U64(v));
                 Ok(())
             }
 
             fn i64(&mut self, v: i64) -> Result<(), Error> {
-                self.0 = Some(TestToken::I64(v));
+                self.token = Some(TestToken::I64(v));
                 Ok(())
             }
 
             fn u128(&mut self, v: &u128) -> Result<(), Error> {
-                self.0 = Some(TestToken::U128(*v));
+                self.token = Some(TestToken::U128(*v));
                 Ok(())
             }
 
             fn i128(&mut self, v: &i128) -> Result<(), Error> {
-                self.0 = Some(TestToken::I128(*v));
+                self.token = Some(TestToken::I128(*v));
                 Ok(())
             }
 
             fn f64(&mut self, v: f64) -> Result<(), Error> {
-                self.0 = Some(TestToken::F64(v));
+                self.token = Some(TestToken::F64(v));
                 Ok(())
             }
 
             fn bool(&mut self, v: bool) -> Result<(), Error> {
-                self.0 = Some(TestToken::Bool(v));
+                self.token = Some(TestToken::Bool(v));
                 Ok(())
             }
 
             fn char(&mut self, v: char) -> Result<(), Error> {
-                self.0 = Some(TestToken::Char(v));
+                self.token = Some(TestToken::Char(v));
                 Ok(())
             }
 
             fn str(&mut self, v: &str) -> Result<(), Error> {
-                self.0 = Some(TestToken::Str(v.into()));
+                self.token = Some(TestToken::Str(v.into()));
+                Ok(())
+            }
+
+            fn borrowed_str(&mut self, v: &'v str) -> Result<(), Error> {
+                if self.detailed {
+                    self.token = Some(TestToken::BorrowedStr(v.into()));
+                    Ok(())
+                } else {
+                    self.str(v)
+                }
+            }
+
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                self.token = Some(TestToken::Bytes(v.into()));
                 Ok(())
             }
 
             fn none(&mut self) -> Result<(), Error> {
-                self.0 = Some(TestToken::None);
+                self.token = Some(TestToken::None);
                 Ok(())
             }
 
             #[cfg(feature = "error")]
             fn error(&mut self, _: &dyn internal::error::Error) -> Result<(), Error> {
-                self.0 = Some(TestToken::Error);
+                self.token = Some(TestToken::Error);
                 Ok(())
             }
 
             #[cfg(feature = "sval2")]
             fn sval2(&mut self, _: &dyn internal::sval::v2::Value) -> Result<(), Error> {
-                self.0 = Some(TestToken::Sval { version: 2 });
+                self.token = Some(TestToken::Sval { version: 2 });
                 Ok(())
             }
 
             #[cfg(feature = "serde1")]
             fn serde1(&mut self, _: &dyn internal::serde::v1::Serialize) -> Result<(), Error> {
-                self.0 = Some(TestToken::Serde { version: 1 });
+                self.token = Some(TestToken::Serde { version: 1 });
                 Ok(())
             }
 
             #[cfg(feature = "seq")]
             fn seq(&mut self, _: &dyn internal::seq::Seq) -> Result<(), Error> {
-                self.0 = Some(TestToken::Seq);
+                self.token = Some(TestToken::Seq);
+                Ok(())
+            }
+
+            #[cfg(feature = "map")]
+            fn map(&mut self, _: &dyn internal::map::Map) -> Result<(), Error> {
+                self.token = Some(TestToken::Map);
                 Ok(())
             }
 
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
-                self.0 = Some(TestToken::Poisoned(msg.into()));
+                self.token = Some(TestToken::Poisoned(msg.into()));
                 Ok(())
             }
         }
 
-        let mut visitor = TestVisitor(None);
+        let mut visitor = TestVisitor {
+            token: None,
+            detailed,
+        };
         self.internal_visit(&mut visitor).unwrap();
 
-        visitor.0.unwrap()
+        visitor.token.unwrap()
     }
 }
 
+#[cfg(test)]
 pub(crate) struct TestVisit {
     pub i64: i64,
     pub u64: u64,
@@ -175,6 +226,7 @@ pub(crate) struct TestVisit {
     pub char: char,
 }
 
+#[cfg(test)]
 impl Default for TestVisit {
     fn default() -> Self {
         TestVisit {
@@ -191,6 +243,7 @@ impl Default for TestVisit {
     }
 }
 
+#[cfg(test)]
 impl<'v> Visit<'v> for TestVisit {
     fn visit_any(&mut self, v: ValueBag) -> Result<(), Error> {
         panic!("unexpected value: {}", v)
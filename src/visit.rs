@@ -60,6 +60,7 @@
 
 use crate::{
     internal::{self, InternalVisitor},
+    std::any::TypeId,
     Error, ValueBag,
 };
 
@@ -73,6 +74,49 @@ pub trait Visit<'v> {
     /// or serialized using its `sval::Value` or `serde::Serialize` implementation.
     fn visit_any(&mut self, value: ValueBag) -> Result<(), Error>;
 
+    /// Visit a `ValueBag` along with the `TypeId` it was captured from.
+    ///
+    /// This method is called instead of `visit_any` for values that aren't primitives,
+    /// and for primitives that were captured from a concrete type, like a `u8` passed
+    /// to `capture_debug`. The `type_id` is `Some` when the value was captured using one
+    /// of the `capture_*` methods, and `None` when it was captured anonymously, such as
+    /// through `from_debug`. The default implementation ignores the type id and visits
+    /// primitives using their more specific methods.
+    #[inline]
+    fn visit_typed(&mut self, type_id: Option<TypeId>, value: ValueBag) -> Result<(), Error> {
+        let _ = type_id;
+        value.visit(Unborrowed {
+            visitor: self,
+            typed: false,
+        })
+    }
+
+    /// Visit a primitive `ValueBag` that was captured with a tag.
+    ///
+    /// Tags are hints from frameworks like `sval` about how a value should be interpreted,
+    /// such as a string containing an arbitrary-precision number. The `tag` is `Some` for
+    /// well-known tags, like `"NUMBER"`, and `None` for tags without a public name.
+    /// The default implementation ignores the tag and visits the value using its more
+    /// specific method.
+    #[inline]
+    fn visit_tagged(&mut self, tag: Option<&str>, value: ValueBag) -> Result<(), Error> {
+        let _ = tag;
+        value.visit(Unborrowed {
+            visitor: self,
+            typed: true,
+        })
+    }
+
+    /// Visit a primitive `ValueBag` that was captured with a tag.
+    #[inline]
+    fn visit_borrowed_tagged(
+        &mut self,
+        tag: Option<&str>,
+        value: ValueBag<'v>,
+    ) -> Result<(), Error> {
+        self.visit_tagged(tag, value)
+    }
+
     /// Visit an empty value.
     #[inline]
     fn visit_empty(&mut self) -> Result<(), Error> {
@@ -92,6 +136,31 @@ pub trait Visit<'v> {
     }
 
     /// Visit a big unsigned integer.
+    ///
+    /// ```
+    /// # fn main() -> Result<(), value_bag::Error> {
+    /// use value_bag::{visit::Visit, Error, ValueBag};
+    ///
+    /// struct U128(Option<u128>);
+    ///
+    /// impl<'v> Visit<'v> for U128 {
+    ///     fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+    ///         Err(Error::msg("not a u128"))
+    ///     }
+    ///
+    ///     fn visit_u128(&mut self, v: u128) -> Result<(), Error> {
+    ///         self.0 = Some(v);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut visitor = U128(None);
+    /// ValueBag::from(&u128::MAX).visit(&mut visitor)?;
+    ///
+    /// assert_eq!(Some(u128::MAX), visitor.0);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
     fn visit_u128(&mut self, value: u128) -> Result<(), Error> {
         self.visit_any((&value).into())
@@ -134,6 +203,18 @@ pub trait Visit<'v> {
         self.visit_str(&*value.encode_utf8(&mut b))
     }
 
+    /// Visit a byte buffer.
+    #[inline]
+    fn visit_bytes(&mut self, value: &[u8]) -> Result<(), Error> {
+        self.visit_any(ValueBag::from_bytes(value))
+    }
+
+    /// Visit a byte buffer.
+    #[inline]
+    fn visit_borrowed_bytes(&mut self, value: &'v [u8]) -> Result<(), Error> {
+        self.visit_bytes(value)
+    }
+
     /// Visit an error.
     #[inline]
     #[cfg(feature = "error")]
@@ -150,9 +231,47 @@ pub trait Visit<'v> {
     ) -> Result<(), Error> {
         self.visit_any(ValueBag::from_dyn_error(err))
     }
+
+    /// Visit the backtrace captured by an error.
+    ///
+    /// This method is called after [`Visit::visit_error`] or [`Visit::visit_borrowed_error`]
+    /// when the error provides a captured backtrace. Errors can only provide backtraces on
+    /// nightly compilers, so this method is only available when building with
+    /// `RUSTFLAGS="--cfg value_bag_unstable_error_backtrace"`.
+    #[inline]
+    #[cfg(all(feature = "error", value_bag_unstable_error_backtrace))]
+    fn visit_error_backtrace(&mut self, backtrace: &str) -> Result<(), Error> {
+        let _ = backtrace;
+        Ok(())
+    }
+
+    /// Visit an element of a sequence along with its index.
+    ///
+    /// This method is called by [`ValueBag::visit_seq`] for each element in a sequence.
+    /// The default implementation ignores the index and visits the element itself.
+    #[inline]
+    #[cfg(feature = "seq")]
+    fn visit_seq_element(&mut self, index: usize, value: ValueBag) -> Result<(), Error> {
+        let _ = index;
+        value.visit(Unborrowed {
+            visitor: self,
+            typed: true,
+        })
+    }
+
+    /// Visit an element of a sequence along with its index.
+    #[inline]
+    #[cfg(feature = "seq")]
+    fn visit_borrowed_seq_element(
+        &mut self,
+        index: usize,
+        value: ValueBag<'v>,
+    ) -> Result<(), Error> {
+        self.visit_seq_element(index, value)
+    }
 }
 
-impl<'a, 'v, T: ?Sized> Visit<'v> for &'a mut T
+impl<'v, T: ?Sized> Visit<'v> for &mut T
 where
     T: Visit<'v>,
 {
@@ -161,6 +280,25 @@ where
         (**self).visit_any(value)
     }
 
+    #[inline]
+    fn visit_typed(&mut self, type_id: Option<TypeId>, value: ValueBag) -> Result<(), Error> {
+        (**self).visit_typed(type_id, value)
+    }
+
+    #[inline]
+    fn visit_tagged(&mut self, tag: Option<&str>, value: ValueBag) -> Result<(), Error> {
+        (**self).visit_tagged(tag, value)
+    }
+
+    #[inline]
+    fn visit_borrowed_tagged(
+        &mut self,
+        tag: Option<&str>,
+        value: ValueBag<'v>,
+    ) -> Result<(), Error> {
+        (**self).visit_borrowed_tagged(tag, value)
+    }
+
     #[inline]
     fn visit_empty(&mut self) -> Result<(), Error> {
         (**self).visit_empty()
@@ -211,6 +349,16 @@ where
         (**self).visit_char(value)
     }
 
+    #[inline]
+    fn visit_bytes(&mut self, value: &[u8]) -> Result<(), Error> {
+        (**self).visit_bytes(value)
+    }
+
+    #[inline]
+    fn visit_borrowed_bytes(&mut self, value: &'v [u8]) -> Result<(), Error> {
+        (**self).visit_borrowed_bytes(value)
+    }
+
     #[inline]
     #[cfg(feature = "error")]
     fn visit_error(&mut self, err: &(dyn crate::std::error::Error + 'static)) -> Result<(), Error> {
@@ -225,6 +373,112 @@ where
     ) -> Result<(), Error> {
         (**self).visit_borrowed_error(err)
     }
+
+    #[inline]
+    #[cfg(all(feature = "error", value_bag_unstable_error_backtrace))]
+    fn visit_error_backtrace(&mut self, backtrace: &str) -> Result<(), Error> {
+        (**self).visit_error_backtrace(backtrace)
+    }
+
+    #[inline]
+    #[cfg(feature = "seq")]
+    fn visit_seq_element(&mut self, index: usize, value: ValueBag) -> Result<(), Error> {
+        (**self).visit_seq_element(index, value)
+    }
+
+    #[inline]
+    #[cfg(feature = "seq")]
+    fn visit_borrowed_seq_element(
+        &mut self,
+        index: usize,
+        value: ValueBag<'v>,
+    ) -> Result<(), Error> {
+        (**self).visit_borrowed_seq_element(index, value)
+    }
+}
+
+/*
+Visits values with any lifetime using a visitor for a specific one.
+Borrowed values are visited using their non-borrowed methods.
+When `typed` is `false`, values without a more specific method are visited
+using `visit_any`, even if they have a type id.
+*/
+struct Unborrowed<'a, V: ?Sized> {
+    visitor: &'a mut V,
+    typed: bool,
+}
+
+impl<'a, 'b, 'v, V: Visit<'v> + ?Sized> Visit<'b> for Unborrowed<'a, V> {
+    fn visit_any(&mut self, value: ValueBag) -> Result<(), Error> {
+        self.visitor.visit_any(value)
+    }
+
+    fn visit_typed(&mut self, type_id: Option<TypeId>, value: ValueBag) -> Result<(), Error> {
+        if self.typed {
+            self.visitor.visit_typed(type_id, value)
+        } else {
+            self.visitor.visit_any(value)
+        }
+    }
+
+    fn visit_tagged(&mut self, tag: Option<&str>, value: ValueBag) -> Result<(), Error> {
+        self.visitor.visit_tagged(tag, value)
+    }
+
+    fn visit_empty(&mut self) -> Result<(), Error> {
+        self.visitor.visit_empty()
+    }
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), Error> {
+        self.visitor.visit_u64(value)
+    }
+
+    fn visit_i64(&mut self, value: i64) -> Result<(), Error> {
+        self.visitor.visit_i64(value)
+    }
+
+    fn visit_u128(&mut self, value: u128) -> Result<(), Error> {
+        self.visitor.visit_u128(value)
+    }
+
+    fn visit_i128(&mut self, value: i128) -> Result<(), Error> {
+        self.visitor.visit_i128(value)
+    }
+
+    fn visit_f64(&mut self, value: f64) -> Result<(), Error> {
+        self.visitor.visit_f64(value)
+    }
+
+    fn visit_bool(&mut self, value: bool) -> Result<(), Error> {
+        self.visitor.visit_bool(value)
+    }
+
+    fn visit_str(&mut self, value: &str) -> Result<(), Error> {
+        self.visitor.visit_str(value)
+    }
+
+    fn visit_char(&mut self, value: char) -> Result<(), Error> {
+        self.visitor.visit_char(value)
+    }
+
+    fn visit_bytes(&mut self, value: &[u8]) -> Result<(), Error> {
+        self.visitor.visit_bytes(value)
+    }
+
+    #[cfg(feature = "error")]
+    fn visit_error(&mut self, err: &(dyn crate::std::error::Error + 'static)) -> Result<(), Error> {
+        self.visitor.visit_error(err)
+    }
+
+    #[cfg(all(feature = "error", value_bag_unstable_error_backtrace))]
+    fn visit_error_backtrace(&mut self, backtrace: &str) -> Result<(), Error> {
+        self.visitor.visit_error_backtrace(backtrace)
+    }
+
+    #[cfg(feature = "seq")]
+    fn visit_seq_element(&mut self, index: usize, value: ValueBag) -> Result<(), Error> {
+        self.visitor.visit_seq_element(index, value)
+    }
 }
 
 impl<'v> ValueBag<'v> {
@@ -234,7 +488,81 @@ impl<'v> ValueBag<'v> {
     /// It's useful for simple cases where a full framework like `serde` or `sval`
     /// isn't necessary.
     pub fn visit(&self, visitor: impl Visit<'v>) -> Result<(), Error> {
-        struct Visitor<V>(V);
+        struct Visitor<V> {
+            visitor: V,
+            type_id: Option<TypeId>,
+            #[cfg(feature = "sval2")]
+            tag: Option<Option<&'static str>>,
+        }
+
+        impl<'v, V> Visitor<V>
+        where
+            V: Visit<'v>,
+        {
+            fn primitive(
+                &mut self,
+                value: ValueBag,
+                visit: impl FnOnce(&mut V) -> Result<(), Error>,
+            ) -> Result<(), Error> {
+                #[cfg(feature = "sval2")]
+                {
+                    if let Some(tag) = self.tag.take() {
+                        return self.visitor.visit_tagged(tag, value);
+                    }
+                }
+                if let Some(type_id) = self.type_id.take() {
+                    return self.visitor.visit_typed(Some(type_id), value);
+                }
+
+                visit(&mut self.visitor)
+            }
+
+            fn borrowed_primitive(
+                &mut self,
+                value: ValueBag<'v>,
+                visit: impl FnOnce(&mut V) -> Result<(), Error>,
+            ) -> Result<(), Error> {
+                #[cfg(feature = "sval2")]
+                {
+                    if let Some(tag) = self.tag.take() {
+                        return self.visitor.visit_borrowed_tagged(tag, value);
+                    }
+                }
+                #[cfg(not(feature = "sval2"))]
+                {
+                    let _ = value;
+                }
+
+                visit(&mut self.visitor)
+            }
+
+            #[cfg(feature = "error")]
+            fn backtrace(
+                &mut self,
+                err: &(dyn internal::error::Error + 'static),
+            ) -> Result<(), Error> {
+                #[cfg(value_bag_unstable_error_backtrace)]
+                {
+                    use crate::std::{
+                        backtrace::{Backtrace, BacktraceStatus},
+                        error,
+                        string::ToString,
+                    };
+
+                    if let Some(backtrace) = error::request_ref::<Backtrace>(err) {
+                        if backtrace.status() == BacktraceStatus::Captured {
+                            return self.visitor.visit_error_backtrace(&backtrace.to_string());
+                        }
+                    }
+                }
+                #[cfg(not(value_bag_unstable_error_backtrace))]
+                {
+                    let _ = err;
+                }
+
+                Ok(())
+            }
+        }
 
         impl<'v, V> InternalVisitor<'v> for Visitor<V>
         where
@@ -245,56 +573,71 @@ impl<'v> ValueBag<'v> {
             }
 
             fn debug(&mut self, v: &dyn internal::fmt::Debug) -> Result<(), Error> {
-                self.0.visit_any(ValueBag::from_dyn_debug(v))
+                self.visitor
+                    .visit_typed(self.type_id, ValueBag::from_dyn_debug(v))
             }
 
             fn display(&mut self, v: &dyn internal::fmt::Display) -> Result<(), Error> {
-                self.0.visit_any(ValueBag::from_dyn_display(v))
+                self.visitor
+                    .visit_typed(self.type_id, ValueBag::from_dyn_display(v))
             }
 
             fn u64(&mut self, v: u64) -> Result<(), Error> {
-                self.0.visit_u64(v)
+                self.primitive(ValueBag::from_u64(v), |visitor| visitor.visit_u64(v))
             }
 
             fn i64(&mut self, v: i64) -> Result<(), Error> {
-                self.0.visit_i64(v)
+                self.primitive(ValueBag::from_i64(v), |visitor| visitor.visit_i64(v))
             }
 
             fn u128(&mut self, v: &u128) -> Result<(), Error> {
-                self.0.visit_u128(*v)
+                self.primitive(ValueBag::from_u128_ref(v), |visitor| visitor.visit_u128(*v))
             }
 
             fn i128(&mut self, v: &i128) -> Result<(), Error> {
-                self.0.visit_i128(*v)
+                self.primitive(ValueBag::from_i128_ref(v), |visitor| visitor.visit_i128(*v))
             }
 
             fn f64(&mut self, v: f64) -> Result<(), Error> {
-                self.0.visit_f64(v)
+                self.primitive(ValueBag::from_f64(v), |visitor| visitor.visit_f64(v))
             }
 
             fn bool(&mut self, v: bool) -> Result<(), Error> {
-                self.0.visit_bool(v)
+                self.primitive(ValueBag::from_bool(v), |visitor| visitor.visit_bool(v))
             }
 
             fn char(&mut self, v: char) -> Result<(), Error> {
-                self.0.visit_char(v)
+                self.primitive(ValueBag::from_char(v), |visitor| visitor.visit_char(v))
             }
 
             fn str(&mut self, v: &str) -> Result<(), Error> {
-                self.0.visit_str(v)
+                self.primitive(ValueBag::from_str(v), |visitor| visitor.visit_str(v))
             }
 
             fn borrowed_str(&mut self, v: &'v str) -> Result<(), Error> {
-                self.0.visit_borrowed_str(v)
+                self.borrowed_primitive(ValueBag::from_str(v), |visitor| {
+                    visitor.visit_borrowed_str(v)
+                })
+            }
+
+            fn bytes(&mut self, v: &[u8]) -> Result<(), Error> {
+                self.primitive(ValueBag::from_bytes(v), |visitor| visitor.visit_bytes(v))
+            }
+
+            fn borrowed_bytes(&mut self, v: &'v [u8]) -> Result<(), Error> {
+                self.borrowed_primitive(ValueBag::from_bytes(v), |visitor| {
+                    visitor.visit_borrowed_bytes(v)
+                })
             }
 
             fn none(&mut self) -> Result<(), Error> {
-                self.0.visit_empty()
+                self.primitive(ValueBag::empty(), |visitor| visitor.visit_empty())
             }
 
             #[cfg(feature = "error")]
             fn error(&mut self, v: &(dyn internal::error::Error + 'static)) -> Result<(), Error> {
-                self.0.visit_error(v)
+                self.visitor.visit_error(v)?;
+                self.backtrace(v)
             }
 
             #[cfg(feature = "error")]
@@ -302,7 +645,8 @@ impl<'v> ValueBag<'v> {
                 &mut self,
                 v: &'v (dyn internal::error::Error + 'static),
             ) -> Result<(), Error> {
-                self.0.visit_borrowed_error(v)
+                self.visitor.visit_borrowed_error(v)?;
+                self.backtrace(v)
             }
 
             #[cfg(feature = "sval2")]
@@ -310,7 +654,8 @@ impl<'v> ValueBag<'v> {
                 if internal::sval::v2::internal_visit(v, self) {
                     Ok(())
                 } else {
-                    self.0.visit_any(ValueBag::from_dyn_sval2(v))
+                    self.visitor
+                        .visit_typed(self.type_id, ValueBag::from_dyn_sval2(v))
                 }
             }
 
@@ -322,7 +667,8 @@ impl<'v> ValueBag<'v> {
                 if internal::sval::v2::borrowed_internal_visit(v, self) {
                     Ok(())
                 } else {
-                    self.0.visit_any(ValueBag::from_dyn_sval2(v))
+                    self.visitor
+                        .visit_typed(self.type_id, ValueBag::from_dyn_sval2(v))
                 }
             }
 
@@ -331,13 +677,27 @@ impl<'v> ValueBag<'v> {
                 if internal::serde::v1::internal_visit(v, self) {
                     Ok(())
                 } else {
-                    self.0.visit_any(ValueBag::from_dyn_serde1(v))
+                    self.visitor
+                        .visit_typed(self.type_id, ValueBag::from_dyn_serde1(v))
                 }
             }
 
             #[cfg(feature = "seq")]
             fn seq(&mut self, v: &dyn internal::seq::Seq) -> Result<(), Error> {
-                self.0.visit_any(ValueBag::from_dyn_seq(v))
+                self.visitor
+                    .visit_typed(self.type_id, ValueBag::from_dyn_seq(v))
+            }
+
+            #[cfg(feature = "map")]
+            fn map(&mut self, v: &dyn internal::map::Map) -> Result<(), Error> {
+                self.visitor
+                    .visit_typed(self.type_id, ValueBag::from_dyn_map(v))
+            }
+
+            #[cfg(feature = "sval2")]
+            fn sval2_tag(&mut self, tag: Option<&'static str>) -> Result<(), Error> {
+                self.tag = Some(tag);
+                Ok(())
             }
 
             fn poisoned(&mut self, msg: &'static str) -> Result<(), Error> {
@@ -345,8 +705,289 @@ impl<'v> ValueBag<'v> {
             }
         }
 
-        self.internal_visit(&mut Visitor(visitor))
+        self.internal_visit(&mut Visitor {
+            visitor,
+            type_id: self.type_id(),
+            #[cfg(feature = "sval2")]
+            tag: None,
+        })
+    }
+
+    /// Visit the elements of this value as a sequence using a simple visitor.
+    ///
+    /// Each element is passed to [`Visit::visit_seq_element`] along with its index,
+    /// starting from `0`. Visiting stops at the first element that returns an error.
+    /// If this value is not a sequence then this method will return an error.
+    #[cfg(feature = "seq")]
+    pub fn visit_seq(&self, visitor: impl Visit<'v>) -> Result<(), Error> {
+        self.visit_seq_elements(visitor, false)
+    }
+
+    /// Visit the elements of this value as a sequence, continuing past elements that fail.
+    ///
+    /// This method is like [`ValueBag::visit_seq`], except every element is visited even
+    /// if some of them return errors. The errors are combined into a single error that's
+    /// returned after all elements have been visited. Without the `std` feature, only the
+    /// first error is returned.
+    #[cfg(feature = "seq")]
+    pub fn visit_seq_collecting_errors(&self, visitor: impl Visit<'v>) -> Result<(), Error> {
+        self.visit_seq_elements(visitor, true)
+    }
+
+    #[cfg(feature = "seq")]
+    fn visit_seq_elements(&self, mut visitor: impl Visit<'v>, collect: bool) -> Result<(), Error> {
+        struct Elements<'a, V> {
+            visitor: &'a mut V,
+            index: usize,
+            collect: bool,
+            errors: Errors,
+        }
+
+        impl<'a, 'v, V: Visit<'v>> Elements<'a, V> {
+            fn element(&mut self, visit: impl FnOnce(&mut V, usize) -> Result<(), Error>) {
+                if self.errors.first.is_none() || self.collect {
+                    if let Err(err) = visit(self.visitor, self.index) {
+                        self.errors.push(self.index, err);
+                    }
+
+                    self.index += 1;
+                }
+            }
+        }
+
+        impl<'a, 'v, V: Visit<'v>> internal::seq::ExtendValue<'v> for Elements<'a, V> {
+            fn extend(&mut self, inner: internal::Internal) {
+                self.element(|visitor, index| visitor.visit_seq_element(index, ValueBag { inner }))
+            }
+
+            fn extend_borrowed(&mut self, inner: internal::Internal<'v>) {
+                self.element(|visitor, index| {
+                    visitor.visit_borrowed_seq_element(index, ValueBag { inner })
+                })
+            }
+        }
+
+        #[derive(Default)]
+        struct Errors {
+            first: Option<Error>,
+            count: usize,
+            #[cfg(feature = "std")]
+            combined: crate::std::string::String,
+        }
+
+        impl Errors {
+            fn push(&mut self, index: usize, err: Error) {
+                #[cfg(feature = "std")]
+                {
+                    use crate::std::fmt::Write as _;
+
+                    let sep = if self.count == 0 { "" } else { "; " };
+                    let _ = write!(self.combined, "{sep}element {index}: {err}");
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    let _ = index;
+                }
+
+                self.count += 1;
+                if self.first.is_none() {
+                    self.first = Some(err);
+                }
+            }
+
+            fn into_result(self) -> Result<(), Error> {
+                match self.first {
+                    None => Ok(()),
+                    Some(err) if self.count == 1 => Err(err),
+                    #[cfg(feature = "std")]
+                    Some(_) => Err(Error::boxed(format!(
+                        "failed to visit {} elements: {}",
+                        self.count, self.combined
+                    ))),
+                    #[cfg(not(feature = "std"))]
+                    Some(err) => Err(err),
+                }
+            }
+        }
+
+        self.inner
+            .extend_from(Elements {
+                visitor: &mut visitor,
+                index: 0,
+                collect,
+                errors: Errors::default(),
+            })
+            .ok_or_else(|| Error::msg("the value is not a sequence"))?
+            .errors
+            .into_result()
+    }
+
+    /// Visit the leaves of this value, flattening any nested maps and sequences.
+    ///
+    /// Each leaf is passed to `visitor` along with its path, which is made of the keys
+    /// of maps and the indexes of sequences joined by `sep`. With a `sep` of `'.'`, the
+    /// map `{ a: { b: 1 } }` is visited as `a.b = 1`. A value that isn't a map or a
+    /// sequence is visited once with an empty path.
+    ///
+    /// Visiting stops at the first leaf that returns an error.
+    #[cfg(all(feature = "alloc", any(feature = "map", feature = "seq")))]
+    pub fn visit_flattened(
+        &self,
+        sep: char,
+        mut visitor: impl FnMut(&str, ValueBag) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        flatten(
+            self,
+            sep,
+            &mut crate::std::string::String::new(),
+            &mut visitor,
+        )
+    }
+
+    /// Get the maximum nesting depth of maps and sequences in this value.
+    ///
+    /// A value that isn't a map or a sequence has a depth of `0`. A map or sequence has
+    /// a depth of one more than the deepest of its keys and values, so the sequence
+    /// `[[1], 2]` has a depth of `2`. This can be used to reject overly deep values.
+    #[cfg(any(feature = "map", feature = "seq"))]
+    pub fn max_depth(&self) -> usize {
+        max_depth(&self.inner)
+    }
+}
+
+#[cfg(any(feature = "map", feature = "seq"))]
+fn max_depth(value: &internal::Internal) -> usize {
+    struct MaxDepth(usize);
+
+    impl MaxDepth {
+        fn nested(&mut self, value: internal::Internal) {
+            self.0 = self.0.max(max_depth(&value));
+        }
+    }
+
+    #[cfg(feature = "map")]
+    impl<'v> internal::map::ExtendEntry<'v> for MaxDepth {
+        fn extend(&mut self, k: internal::Internal, v: internal::Internal) {
+            self.nested(k);
+            self.nested(v);
+        }
+    }
+
+    #[cfg(feature = "seq")]
+    impl<'v> internal::seq::ExtendValue<'v> for MaxDepth {
+        fn extend(&mut self, v: internal::Internal) {
+            self.nested(v)
+        }
+    }
+
+    #[cfg(feature = "map")]
+    {
+        if let Some(map) = value.extend_map_from(MaxDepth(0)) {
+            return map.0 + 1;
+        }
+    }
+
+    #[cfg(feature = "seq")]
+    {
+        if let Some(seq) = value.extend_from(MaxDepth(0)) {
+            return seq.0 + 1;
+        }
+    }
+
+    0
+}
+
+#[cfg(all(feature = "alloc", any(feature = "map", feature = "seq")))]
+fn flatten(
+    value: &ValueBag,
+    sep: char,
+    path: &mut crate::std::string::String,
+    visitor: &mut dyn FnMut(&str, ValueBag) -> Result<(), Error>,
+) -> Result<(), Error> {
+    use crate::std::fmt::{self, Write as _};
+
+    struct Flatten<'a> {
+        sep: char,
+        path: &'a mut crate::std::string::String,
+        visitor: &'a mut dyn FnMut(&str, ValueBag) -> Result<(), Error>,
+        #[cfg(feature = "seq")]
+        index: usize,
+        result: Result<(), Error>,
+    }
+
+    impl<'a> Flatten<'a> {
+        fn new(
+            sep: char,
+            path: &'a mut crate::std::string::String,
+            visitor: &'a mut dyn FnMut(&str, ValueBag) -> Result<(), Error>,
+        ) -> Self {
+            Flatten {
+                sep,
+                path,
+                visitor,
+                #[cfg(feature = "seq")]
+                index: 0,
+                result: Ok(()),
+            }
+        }
+
+        fn nested(&mut self, segment: impl fmt::Display, value: internal::Internal) {
+            if self.result.is_err() {
+                return;
+            }
+
+            let len = self.path.len();
+            if len > 0 {
+                self.path.push(self.sep);
+            }
+            let _ = write!(self.path, "{segment}");
+
+            self.result = flatten(
+                &ValueBag { inner: value },
+                self.sep,
+                self.path,
+                self.visitor,
+            );
+
+            self.path.truncate(len);
+        }
+    }
+
+    #[cfg(feature = "map")]
+    impl<'a, 'v> internal::map::ExtendEntry<'v> for Flatten<'a> {
+        fn extend(&mut self, k: internal::Internal, v: internal::Internal) {
+            self.nested(ValueBag { inner: k }, v)
+        }
+    }
+
+    #[cfg(feature = "seq")]
+    impl<'a, 'v> internal::seq::ExtendValue<'v> for Flatten<'a> {
+        fn extend(&mut self, v: internal::Internal) {
+            let index = self.index;
+            self.index += 1;
+
+            self.nested(index, v)
+        }
+    }
+
+    #[cfg(feature = "map")]
+    {
+        if let Some(map) = value
+            .inner
+            .extend_map_from(Flatten::new(sep, path, visitor))
+        {
+            return map.result;
+        }
     }
+
+    #[cfg(feature = "seq")]
+    {
+        if let Some(seq) = value.inner.extend_from(Flatten::new(sep, path, visitor)) {
+            return seq.result;
+        }
+    }
+
+    visitor(path, value.by_ref())
 }
 
 #[cfg(test)]
@@ -408,6 +1049,60 @@ mod tests {
         assert!(visitor.0);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn visit_typed() {
+        #[derive(Debug)]
+        struct Data;
+
+        struct Visitor(Option<Option<TypeId>>);
+
+        impl<'v> Visit<'v> for Visitor {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn visit_typed(&mut self, type_id: Option<TypeId>, _: ValueBag) -> Result<(), Error> {
+                self.0 = Some(type_id);
+                Ok(())
+            }
+        }
+
+        let mut visitor = Visitor(None);
+        ValueBag::capture_debug(&Data).visit(&mut visitor).unwrap();
+        assert_eq!(Some(Some(TypeId::of::<Data>())), visitor.0);
+
+        let mut visitor = Visitor(None);
+        ValueBag::from_debug(&Data).visit(&mut visitor).unwrap();
+        assert_eq!(Some(None), visitor.0);
+
+        let mut visitor = Visitor(None);
+        ValueBag::capture_debug(&1u8).visit(&mut visitor).unwrap();
+        assert_eq!(Some(Some(TypeId::of::<u8>())), visitor.0);
+
+        let mut visitor = Visitor(None);
+        ValueBag::from(1u8).visit(&mut visitor).unwrap();
+        assert_eq!(None, visitor.0);
+
+        // Visitors that don't handle type ids see the underlying value
+        struct Untyped(Option<u64>);
+
+        impl<'v> Visit<'v> for Untyped {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                panic!("unexpected value")
+            }
+
+            fn visit_u64(&mut self, v: u64) -> Result<(), Error> {
+                self.0 = Some(v);
+                Ok(())
+            }
+        }
+
+        let mut visitor = Untyped(None);
+        ValueBag::capture_debug(&1u8).visit(&mut visitor).unwrap();
+        assert_eq!(Some(1), visitor.0);
+    }
+
     #[test]
     #[cfg(feature = "serde1")]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
@@ -505,4 +1200,250 @@ mod tests {
 
         assert_eq!("{ \"a\": 1, \"b\": 2, \"c\": 3 }", visitor.0);
     }
+    #[test]
+    #[cfg(feature = "sval2")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn visit_sval2_tagged() {
+        struct Number(&'static str);
+
+        impl value_bag_sval2::lib::Value for Number {
+            fn stream<'sval, S: value_bag_sval2::lib::Stream<'sval> + ?Sized>(
+                &'sval self,
+                stream: &mut S,
+            ) -> value_bag_sval2::lib::Result {
+                stream.tagged_begin(Some(&value_bag_sval2::lib::tags::NUMBER), None, None)?;
+                stream.value(self.0)?;
+                stream.tagged_end(Some(&value_bag_sval2::lib::tags::NUMBER), None, None)
+            }
+        }
+
+        struct Tagged(Option<&'static str>);
+
+        impl<'v> Visit<'v> for Tagged {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                panic!("unexpected value")
+            }
+
+            fn visit_borrowed_tagged(
+                &mut self,
+                tag: Option<&str>,
+                value: ValueBag<'v>,
+            ) -> Result<(), Error> {
+                assert_eq!(Some("NUMBER"), tag);
+                assert_eq!(Some("123.456"), value.to_borrowed_str());
+
+                self.0 = Some("NUMBER");
+                Ok(())
+            }
+        }
+
+        let mut visitor = Tagged(None);
+        ValueBag::from_sval2(&Number("123.456"))
+            .visit(&mut visitor)
+            .unwrap();
+        assert_eq!(Some("NUMBER"), visitor.0);
+
+        // Visitors that don't handle tags see the underlying value
+        struct Untagged(bool);
+
+        impl<'v> Visit<'v> for Untagged {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                panic!("unexpected value")
+            }
+
+            fn visit_str(&mut self, v: &str) -> Result<(), Error> {
+                assert_eq!("123.456", v);
+
+                self.0 = true;
+                Ok(())
+            }
+        }
+
+        let mut visitor = Untagged(false);
+        ValueBag::from_sval2(&Number("123.456"))
+            .visit(&mut visitor)
+            .unwrap();
+        assert!(visitor.0);
+    }
+
+    #[test]
+    #[cfg(feature = "seq")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn visit_seq_index() {
+        struct Indexes([(usize, u64); 3]);
+
+        impl<'v> Visit<'v> for Indexes {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                panic!("unexpected value")
+            }
+
+            fn visit_seq_element(&mut self, index: usize, value: ValueBag) -> Result<(), Error> {
+                self.0[index] = (index, value.to_u64().unwrap());
+                Ok(())
+            }
+        }
+
+        let mut visitor = Indexes([(usize::MAX, 0); 3]);
+        ValueBag::from_seq_slice(&[1u64, 2, 3])
+            .visit_seq(&mut visitor)
+            .unwrap();
+        assert_eq!([(0, 1), (1, 2), (2, 3)], visitor.0);
+
+        assert!(ValueBag::from(1u64).visit_seq(&mut visitor).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "seq", feature = "std"))]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn visit_seq_collecting_errors() {
+        use crate::std::string::ToString;
+
+        struct Odd(usize);
+
+        impl<'v> Visit<'v> for Odd {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                panic!("unexpected value")
+            }
+
+            fn visit_seq_element(&mut self, _: usize, value: ValueBag) -> Result<(), Error> {
+                self.0 += 1;
+
+                if value.to_u64().unwrap() & 1 == 0 {
+                    Ok(())
+                } else {
+                    Err(Error::msg("odd number"))
+                }
+            }
+        }
+
+        let value = ValueBag::from_seq_slice(&[1u64, 2, 3]);
+
+        let mut visitor = Odd(0);
+        let err = value.visit_seq(&mut visitor).unwrap_err();
+        assert_eq!(1, visitor.0);
+        assert_eq!("odd number", err.to_string());
+
+        let mut visitor = Odd(0);
+        let err = value.visit_seq_collecting_errors(&mut visitor).unwrap_err();
+        assert_eq!(3, visitor.0);
+        assert_eq!(
+            "failed to visit 2 elements: element 0: odd number; element 2: odd number",
+            err.to_string()
+        );
+
+        assert!(ValueBag::from_seq_slice(&[2u64, 4])
+            .visit_seq_collecting_errors(Odd(0))
+            .is_ok());
+    }
+
+    #[test]
+    #[cfg(all(feature = "error", value_bag_unstable_error_backtrace))]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn visit_error_backtrace() {
+        use crate::std::{
+            backtrace::Backtrace,
+            error::{self, Request},
+            fmt,
+        };
+
+        #[derive(Debug)]
+        struct WithBacktrace(Backtrace);
+
+        impl fmt::Display for WithBacktrace {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an error")
+            }
+        }
+
+        impl error::Error for WithBacktrace {
+            fn provide<'a>(&'a self, request: &mut Request<'a>) {
+                request.provide_ref::<Backtrace>(&self.0);
+            }
+        }
+
+        #[derive(Default)]
+        struct Visitor {
+            error: bool,
+            backtrace: bool,
+        }
+
+        impl<'v> Visit<'v> for Visitor {
+            fn visit_any(&mut self, _: ValueBag) -> Result<(), Error> {
+                Err(Error::msg("unexpected value"))
+            }
+
+            fn visit_borrowed_error(
+                &mut self,
+                _: &'v (dyn error::Error + 'static),
+            ) -> Result<(), Error> {
+                self.error = true;
+                Ok(())
+            }
+
+            fn visit_error_backtrace(&mut self, backtrace: &str) -> Result<(), Error> {
+                assert!(!backtrace.is_empty());
+
+                self.backtrace = true;
+                Ok(())
+            }
+        }
+
+        let err = WithBacktrace(Backtrace::force_capture());
+
+        let mut visitor = Visitor::default();
+        ValueBag::from_dyn_error(&err).visit(&mut visitor).unwrap();
+
+        assert!(visitor.error);
+        assert!(visitor.backtrace);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "map", feature = "seq"))]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn visit_flattened() {
+        use crate::std::{string::String, vec::Vec};
+
+        let b = [("b", ValueBag::from(1u64))];
+        let c = [2u64, 3];
+        let a = [("a", ValueBag::from_entries(&b)), ("c", ValueBag::from(&c))];
+
+        let mut visited = Vec::new();
+        ValueBag::from_entries(&a)
+            .visit_flattened('.', |path, value| {
+                visited.push((String::from(path), value.to_u64()));
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (String::from("a.b"), Some(1)),
+                (String::from("c.0"), Some(2)),
+                (String::from("c.1"), Some(3)),
+            ],
+            visited
+        );
+
+        let mut visited = Vec::new();
+        ValueBag::from(42u64)
+            .visit_flattened('.', |path, value| {
+                visited.push((String::from(path), value.to_u64()));
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(vec![(String::new(), Some(42))], visited);
+    }
+
+    #[test]
+    #[cfg(all(feature = "map", feature = "seq"))]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn max_depth() {
+        let b = [1u64, 2];
+        let a = [("a", ValueBag::from(&b)), ("c", ValueBag::from(3u64))];
+
+        assert_eq!(2, ValueBag::from_entries(&a).max_depth());
+        assert_eq!(1, ValueBag::from(&b).max_depth());
+        assert_eq!(0, ValueBag::from(42u64).max_depth());
+    }
 }